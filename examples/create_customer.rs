@@ -21,8 +21,10 @@ fn main() {
             business_vat_id: None,
             coupon: None,
             description: None,
+            invoice_settings: None,
             metadata: None,
             shipping: None,
+            test_clock: None,
         },
     ).unwrap();
 
@@ -16,6 +16,7 @@ fn main() {
             ending_before: None,
             limit: Some(3),
             starting_after: None,
+            test_clock: None,
         },
     ).unwrap();
 
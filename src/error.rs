@@ -13,12 +13,42 @@ use std::num::ParseIntError;
 pub enum Error {
     /// An error reported by Stripe.
     Stripe(RequestError),
+    /// An idempotency key was reused with different request parameters.
+    Idempotency(RequestError),
+    /// A request which the secret key isn't permitted to make (eg. a restricted key without
+    /// the required permissions, or a `Stripe-Account` which the key can't act as).
+    Permission(RequestError),
     /// A networking error communicating with the Stripe server.
     Http(hyper::Error),
     /// An error reading the response body.
     Io(io::Error),
+    /// An error decompressing a gzip-encoded response body (eg. a truncated or corrupt body).
+    Decompression(io::Error),
     /// An error converting between wire format and Rust types.
     Conversion(Box<error::Error + Send>),
+    /// A secret key which is missing or malformed.
+    InvalidSecretKey(&'static str),
+    /// A request which the client refused to send (eg. a test-only endpoint with a live key).
+    Unsupported(&'static str),
+    /// An operation which the object's current state doesn't allow (eg. canceling a paid payout).
+    InvalidOperation(&'static str),
+    /// An operation which did not complete within the allotted time.
+    Timeout,
+    /// A response for live data when test data was expected (or vice versa).
+    LivemodeMismatch { expected: bool, actual: bool },
+    /// A response body larger than the client's limit.
+    ///
+    /// `received_at_least` is the body's `Content-Length` when it was sent one, or else the
+    /// bytes read before giving up (the rest of the body is never read).
+    ResponseTooLarge { limit: usize, received_at_least: usize },
+    /// A list with more items than the caller's `max_items` cap.
+    TooManyItems { max_items: usize },
+    /// A request made on a worker thread which panicked before reporting its result.
+    WorkerPanicked(String),
+    /// An operation which failed partway, and whose rollback also failed.
+    ///
+    /// The earlier steps of the operation may have taken effect, so they need to be undone by hand.
+    Rollback { error: Box<Error>, rollback: Box<Error> },
 }
 
 impl fmt::Display for Error {
@@ -26,9 +56,23 @@ impl fmt::Display for Error {
         f.write_str(error::Error::description(self))?;
         match *self {
             Error::Stripe(ref err) => write!(f, ": {}", err),
+            Error::Idempotency(ref err) => write!(f, ": {}", err),
+            Error::Permission(ref err) => write!(f, ": {}", err),
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
+            Error::Decompression(ref err) => write!(f, ": {}", err),
             Error::Conversion(ref err) => write!(f, ": {}", err),
+            Error::InvalidSecretKey(ref msg) => write!(f, ": {}", msg),
+            Error::Unsupported(ref msg) => write!(f, ": {}", msg),
+            Error::InvalidOperation(ref msg) => write!(f, ": {}", msg),
+            Error::Timeout => Ok(()),
+            Error::LivemodeMismatch { expected, actual } => write!(f, ": expected livemode {}, got {}", expected, actual),
+            Error::ResponseTooLarge { limit, received_at_least } => {
+                write!(f, ": received at least {} bytes, exceeding {} bytes", received_at_least, limit)
+            }
+            Error::TooManyItems { max_items } => write!(f, ": more than max_items ({})", max_items),
+            Error::WorkerPanicked(ref msg) => write!(f, ": {}", msg),
+            Error::Rollback { ref error, ref rollback } => write!(f, ": {} (after: {})", rollback, error),
         }
     }
 }
@@ -37,25 +81,53 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Stripe(_) => "error reported by stripe",
+            Error::Idempotency(_) => "error due to a reused idempotency key",
+            Error::Permission(_) => "error due to a key without permission for the request",
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
+            Error::Decompression(_) => "error decompressing response from stripe",
             Error::Conversion(_) => "error converting between wire format and Rust types",
+            Error::InvalidSecretKey(_) => "error due to an invalid secret key",
+            Error::Unsupported(_) => "error due to an unsupported request",
+            Error::InvalidOperation(_) => "error due to an operation invalid for the object's state",
+            Error::Timeout => "timed out waiting for stripe",
+            Error::LivemodeMismatch { .. } => "error due to a response in the wrong mode",
+            Error::ResponseTooLarge { .. } => "error due to a response which was too large",
+            Error::TooManyItems { .. } => "error due to a list with too many items",
+            Error::WorkerPanicked(_) => "error due to a worker thread which panicked",
+            Error::Rollback { .. } => "error rolling back a partially completed operation",
         }
     }
 
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             Error::Stripe(ref err) => Some(err),
+            Error::Idempotency(ref err) => Some(err),
+            Error::Permission(ref err) => Some(err),
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
+            Error::Decompression(ref err) => Some(err),
             Error::Conversion(ref err) => Some(&**err),
+            Error::InvalidSecretKey(_) => None,
+            Error::Unsupported(_) => None,
+            Error::InvalidOperation(_) => None,
+            Error::Timeout => None,
+            Error::LivemodeMismatch { .. } => None,
+            Error::ResponseTooLarge { .. } => None,
+            Error::TooManyItems { .. } => None,
+            Error::WorkerPanicked(_) => None,
+            Error::Rollback { ref rollback, .. } => Some(&**rollback),
         }
     }
 }
 
 impl From<RequestError> for Error {
     fn from(err: RequestError) -> Error {
-        Error::Stripe(err)
+        match err.error_type {
+            ErrorType::Idempotency => Error::Idempotency(err),
+            _ if err.is_permission_error() => Error::Permission(err),
+            _ => Error::Stripe(err),
+        }
     }
 }
 
@@ -87,7 +159,7 @@ impl From<json::Error> for Error {
 /// The list of possible values for a RequestError's type.
 #[derive(Debug, PartialEq, Deserialize)]
 pub enum ErrorType {
-    #[serde(skip_deserializing)]
+    #[serde(other)]
     Unknown,
 
     #[serde(rename = "api_error")]
@@ -98,8 +170,12 @@ pub enum ErrorType {
     Authentication,
     #[serde(rename = "card_error")]
     Card,
+    #[serde(rename = "idempotency_error")]
+    Idempotency,
     #[serde(rename = "invalid_request_error")]
     InvalidRequest,
+    #[serde(rename = "permission_error")]
+    Permission,
     #[serde(rename = "rate_limit_error")]
     RateLimit,
     #[serde(rename = "validation_error")]
@@ -134,6 +210,10 @@ pub enum ErrorCode {
     CardDeclined,
     Missing,
     ProcessingError,
+    AccountInvalid,
+    // NOTE: Stripe adds error codes without notice
+    #[serde(other)]
+    Other,
 }
 
 impl fmt::Display for ErrorCode {
@@ -142,6 +222,69 @@ impl fmt::Display for ErrorCode {
     }
 }
 
+/// The list of possible values for a card decline's decline_code.
+///
+/// For more details see https://stripe.com/docs/declines/codes.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclineCode {
+    AuthenticationRequired,
+    ApproveWithId,
+    CallIssuer,
+    CardNotSupported,
+    CardVelocityExceeded,
+    CurrencyNotSupported,
+    DoNotHonor,
+    DoNotTryAgain,
+    DuplicateTransaction,
+    ExpiredCard,
+    Fraudulent,
+    GenericDecline,
+    IncorrectNumber,
+    IncorrectCvc,
+    IncorrectPin,
+    IncorrectZip,
+    InsufficientFunds,
+    InvalidAccount,
+    InvalidAmount,
+    InvalidCvc,
+    InvalidExpiryYear,
+    InvalidNumber,
+    InvalidPin,
+    IssuerNotAvailable,
+    LostCard,
+    MerchantBlacklist,
+    NewAccountInformationAvailable,
+    NoActionTaken,
+    NotPermitted,
+    OfflinePinRequired,
+    OnlineOrOfflinePinRequired,
+    PickupCard,
+    PinTryExceeded,
+    ProcessingError,
+    ReenterTransaction,
+    RestrictedCard,
+    RevocationOfAllAuthorizations,
+    RevocationOfAuthorization,
+    SecurityViolation,
+    ServiceNotAllowed,
+    StolenCard,
+    StopPaymentOrder,
+    TestmodeDecline,
+    TransactionNotAllowed,
+    TryAgainLater,
+    WithdrawalCountLimitExceeded,
+    // NOTE: Stripe adds decline codes without notice
+    #[serde(other)]
+    Other,
+}
+
+impl fmt::Display for DeclineCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+    }
+}
+
 /// An error reported by stripe in a request's response.
 ///
 /// For more details see https://stripe.com/docs/api#errors.
@@ -169,6 +312,9 @@ pub struct RequestError {
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// The parameter the error relates to, if applicable (eg. "currency").
+    pub param: Option<String>,
 }
 
 impl fmt::Display for RequestError {
@@ -189,12 +335,152 @@ impl error::Error for RequestError {
     }
 }
 
+impl RequestError {
+    /// Returns whether the secret key isn't permitted to make the request.
+    ///
+    /// Stripe reports this with a `403 Forbidden` status (eg. for a restricted key without the
+    /// required permissions), or with an `account_invalid` code for a `Stripe-Account` which
+    /// the key can't act as.
+    pub fn is_permission_error(&self) -> bool {
+        self.http_status == 403 || self.error_type == ErrorType::Permission || self.code == Some(ErrorCode::AccountInvalid)
+    }
+
+    /// Returns the parsed `param` of this error, if it has one.
+    pub fn param_path(&self) -> Option<ParamPath> {
+        self.param.as_ref().map(|param| ParamPath::parse(param))
+    }
+
+    /// Returns whether this error's `param` matches `pattern`, ignoring list indices.
+    ///
+    /// For example `"items[].price"` (or `"items[][price]"`) matches a param of `items[0][price]`.
+    /// See `ParamPath::matches`.
+    pub fn param_matches(&self, pattern: &str) -> bool {
+        self.param_path().map_or(false, |path| path.matches(pattern))
+    }
+}
+
+/// A segment of a `ParamPath`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamSegment {
+    /// A field name (eg. `price` in `items[0][price]`).
+    Field(String),
+    /// A list index (eg. `0` in `items[0][price]`).
+    Index(usize),
+}
+
+/// A parsed request param, as reported by a `RequestError` (eg. `items[0][price]`).
+///
+/// The raw param is kept, so the parsing is lossless.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamPath {
+    raw: String,
+    segments: Vec<ParamSegment>,
+}
+
+impl ParamPath {
+    /// Parses a param like `shipping[address][line1]` or `currency`.
+    pub fn parse(raw: &str) -> ParamPath {
+        let segments = parse_param(raw).into_iter()
+            .map(|segment| match segment {
+                Some(segment) => segment,
+                None => ParamSegment::Field(String::new()), // eg. `expand[]`
+            })
+            .collect();
+        ParamPath { raw: raw.to_string(), segments: segments }
+    }
+
+    /// The param as reported by Stripe.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn segments(&self) -> &[ParamSegment] {
+        &self.segments
+    }
+
+    /// Returns whether this param matches `pattern`.
+    ///
+    /// Fields in the pattern may be nested with `.` or brackets, and `[]` matches any list
+    /// index; eg. `items[].price`, `items[][price]` and `items[0].price` all match `items[0][price]`.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = parse_param(pattern);
+        pattern.len() == self.segments.len() && pattern.iter().zip(&self.segments).all(|(expected, actual)| {
+            match (expected, actual) {
+                (&None, &ParamSegment::Index(_)) => true,
+                (&Some(ref expected), actual) => expected == actual,
+                _ => false,
+            }
+        })
+    }
+}
+
+impl fmt::Display for ParamPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+/// Splits a param (or param pattern) into segments, where `None` is an empty `[]`.
+fn parse_param(raw: &str) -> Vec<Option<ParamSegment>> {
+    fn field(name: &str) -> Option<ParamSegment> {
+        match name.parse() {
+            Ok(index) => Some(ParamSegment::Index(index)),
+            Err(_) => Some(ParamSegment::Field(name.to_string())),
+        }
+    }
+
+    let mut segments = Vec::new();
+    let mut rest = raw;
+    while !rest.is_empty() {
+        if rest.starts_with('[') {
+            match rest.find(']') {
+                Some(end) => {
+                    let inner = &rest[1..end];
+                    segments.push(if inner.is_empty() { None } else { field(inner) });
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    // NOTE: Tolerate an unclosed bracket by keeping the rest as a field
+                    segments.push(Some(ParamSegment::Field(rest.to_string())));
+                    break;
+                }
+            }
+        } else if rest.starts_with('.') {
+            rest = &rest[1..];
+        } else {
+            let end = rest.find(|c| c == '[' || c == '.').unwrap_or(rest.len());
+            segments.push(Some(ParamSegment::Field(rest[..end].to_string())));
+            rest = &rest[end..];
+        }
+    }
+    segments
+}
+
 #[doc(hidden)]
 #[derive(Deserialize)]
 pub struct ErrorObject {
     pub error: RequestError,
 }
 
+#[doc(hidden)]
+#[derive(Deserialize)]
+pub struct OAuthErrorObject {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+impl From<OAuthErrorObject> for RequestError {
+    fn from(err: OAuthErrorObject) -> RequestError {
+        let mut req = RequestError::default();
+        req.error_type = ErrorType::InvalidRequest;
+        req.message = Some(match err.error_description {
+            Some(description) => format!("{}: {}", err.error, description),
+            None => err.error,
+        });
+        req
+    }
+}
+
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug)]
 pub enum WebhookError {
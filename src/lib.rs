@@ -50,6 +50,8 @@
 //! ```
 
 extern crate chrono;
+#[cfg(feature = "gzip")]
+extern crate flate2;
 extern crate hmac;
 extern crate hyper;
 #[cfg(feature = "with-rustls")]
@@ -62,13 +64,26 @@ extern crate serde_derive;
 extern crate serde_json;
 extern crate serde_qs;
 extern crate sha2;
+extern crate uuid;
 
 mod client;
 mod error;
+#[cfg(test)]
+mod mock;
 mod resources;
 mod params;
+#[cfg(feature = "test-helpers")]
+pub mod test_helpers;
 
-pub use client::{Client, Params};
-pub use error::{Error, ErrorCode, ErrorType, RequestError};
-pub use params::{List, RangeQuery, RangeBounds, Metadata, Timestamp};
+pub use client::{ByteStream, Client, KeyMode, Params, RequestOutcome, DEFAULT_API_VERSION};
+
+/// A blocking client, so code can name the client the same way as with async Stripe bindings.
+///
+/// This crate's `Client` already blocks on each request without needing a reactor or runtime,
+/// and can be shared between threads, so this is the same type.
+pub mod blocking {
+    pub use client::Client;
+}
+pub use error::{DeclineCode, Error, ErrorCode, ErrorType, ParamPath, ParamSegment, RequestError, WebhookError};
+pub use params::{Expandable, Linked, List, ListParams, Object, RangeQuery, RangeBounds, Metadata, Timestamp};
 pub use resources::*;
@@ -0,0 +1,81 @@
+//! A minimal HTTP server for testing requests without reaching Stripe.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// An api base which nothing listens on, so a request sent to it fails with `Error::Http`.
+///
+/// This checks that a method refuses its arguments without making a request.
+pub const UNREACHABLE: &'static str = "http://127.0.0.1:1/v1";
+
+/// Serves a single response, with extra raw `headers` (eg. "Content-Encoding: gzip\r\n").
+///
+/// Returns the url of the server.
+pub fn serve_once(status: &'static str, headers: &'static str, body: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 4096];
+        let _ = stream.read(&mut request);
+        write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n", status, body.len()).unwrap();
+        stream.write_all(headers.as_bytes()).unwrap();
+        stream.write_all(b"\r\n").unwrap();
+        stream.write_all(&body).unwrap();
+    });
+    format!("http://{}/", addr)
+}
+
+/// Serves each of `responses` (a status and body) to one request, in order.
+///
+/// Returns the api base of the server, and a handle which yields the raw requests received.
+pub fn serve(responses: Vec<(&'static str, String)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let len = stream.read(&mut request).unwrap();
+            requests.push(String::from_utf8_lossy(&request[..len]).into_owned());
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        }
+        requests
+    });
+    (format!("http://{}/v1", addr), server)
+}
+
+/// Returns the paths of the requests received by `serve`.
+pub fn paths(requests: &[String]) -> Vec<&str> {
+    requests.iter().map(|request| request.split(' ').nth(1).unwrap()).collect()
+}
+
+/// Returns the method and path of the requests received by `serve` (eg. "POST /v1/charges").
+pub fn requests(requests: &[String]) -> Vec<String> {
+    requests.iter().map(|request| request.split(' ').take(2).collect::<Vec<_>>().join(" ")).collect()
+}
+
+/// Returns the value of a header of a request received by `serve`.
+pub fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+    request
+        .split("\r\n")
+        .find(|line| line.starts_with(name) && line[name.len()..].starts_with(": "))
+        .map(|line| &line[name.len() + 2..])
+}
+
+/// Wraps bodies as successful responses for `serve`.
+pub fn ok(bodies: Vec<String>) -> Vec<(&'static str, String)> {
+    bodies.into_iter().map(|body| ("200 OK", body)).collect()
+}
+
+/// Returns a minimal charge, as json.
+pub fn charge(id: &str) -> String {
+    format!(r#"{{
+        "id": "{}", "amount": 500, "amount_refunded": 0, "captured": true, "created": 1500000000,
+        "currency": "usd", "fraud_details": {{}}, "livemode": false, "metadata": {{}}, "paid": true,
+        "refunded": false, "refunds": {{"data": [], "has_more": false, "url": "/v1/charges/{}/refunds"}},
+        "source": {{"object": "bitcoin_receiver"}}, "status": "succeeded"
+    }}"#, id, id)
+}
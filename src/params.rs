@@ -1,4 +1,10 @@
+use client::Client;
+use error::Error;
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer, IntoDeserializer};
+use serde::de::value::StrDeserializer;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::ops::Deref;
 
 #[derive(Debug, Deserialize)]
 pub struct List<T> {
@@ -8,6 +14,118 @@ pub struct List<T> {
     pub url: String,
 }
 
+impl<T: DeserializeOwned + Object> List<T> {
+    /// Fetches the items following this page, like `Client::list_all`.
+    ///
+    /// If there are more than `max_items` remaining items, this fails with `Error::TooManyItems`.
+    ///
+    /// This is useful for lists embedded in another object (eg. `Invoice.lines`),
+    /// which only include their first page of items.
+    pub fn remaining(&self, client: &Client, max_items: usize) -> Result<Vec<T>, Error> {
+        match self.data.last() {
+            Some(last) if self.has_more => {
+                // NOTE: The list's url includes the API version prefix (eg. "/v1/invoices/in_123/lines"),
+                //   and may include a query (eg. "/v1/subscription_items?subscription=sub_123")
+                let url = if self.url.starts_with("/v1/") { &self.url[3..] } else { &self.url[..] };
+                let (path, query) = match url.find('?') {
+                    Some(i) => (&url[..i], format!("{}&limit=100", &url[i + 1..])),
+                    None => (url, "limit=100".to_string()),
+                };
+                match client.get_pages(path, &query, Some(last.id()), max_items)? {
+                    (_, true) => Err(Error::TooManyItems { max_items: max_items }),
+                    (items, false) => Ok(items),
+                }
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Implemented by resources which have an id usable as a pagination cursor.
+pub trait Object {
+    fn id(&self) -> &str;
+}
+
+/// Implemented by the parameters of list endpoints, so that pagination can manage their cursor.
+///
+/// NOTE: The params borrow their `starting_after` id, so rather than setting the cursor
+///   of each page on the params, pagination takes the first cursor and appends the rest.
+pub trait ListParams: Serialize {
+    /// Removes the id to start listing after, returning it.
+    fn take_starting_after(&mut self) -> Option<String>;
+
+    /// Sets the page size, unless one was already set.
+    fn default_limit(&mut self, limit: u64);
+}
+
+/// A field which holds an object's id by default, or the full object when expanded.
+///
+/// For more details see https://stripe.com/docs/api#expanding_objects.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    Id(String),
+    Object(Box<T>),
+}
+
+impl<T> Expandable<T> {
+    /// Returns the expanded object, if it was expanded.
+    pub fn as_object(&self) -> Option<&T> {
+        match *self {
+            Expandable::Id(_) => None,
+            Expandable::Object(ref obj) => Some(obj),
+        }
+    }
+
+    /// Returns true if the field holds only an id.
+    pub fn is_id(&self) -> bool {
+        match *self {
+            Expandable::Id(_) => true,
+            Expandable::Object(_) => false,
+        }
+    }
+
+    /// Returns the expanded object, or else fetches the object with `retrieve`.
+    pub fn get_or_fetch<F>(&self, retrieve: F) -> Result<Linked<T>, Error>
+    where
+        F: FnOnce(&str) -> Result<T, Error>,
+    {
+        match *self {
+            Expandable::Id(ref id) => retrieve(id).map(Linked::Fetched),
+            Expandable::Object(ref obj) => Ok(Linked::Expanded(obj)),
+        }
+    }
+}
+
+impl<T: Object> Expandable<T> {
+    /// Returns the id of the object, whether or not it was expanded.
+    pub fn id(&self) -> &str {
+        match *self {
+            Expandable::Id(ref id) => id,
+            Expandable::Object(ref obj) => obj.id(),
+        }
+    }
+}
+
+/// An object reached through an `Expandable` field: either borrowed from the
+/// expanded field, or fetched because the field held only an id.
+#[derive(Debug)]
+pub enum Linked<'a, T: 'a> {
+    Expanded(&'a T),
+    Fetched(T),
+}
+
+impl<'a, T> Deref for Linked<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match *self {
+            Linked::Expanded(obj) => obj,
+            Linked::Fetched(ref obj) => obj,
+        }
+    }
+}
+
 pub type Metadata = HashMap<String, String>;
 pub type Timestamp = i64;
 
@@ -75,6 +193,22 @@ impl<T> RangeQuery<T> {
     }
 }
 
+// NOTE: Stripe adds new enum values without notice, so enums which are both serialized
+//   and deserialized keep an `Other(String)` variant.  Those enums derive with
+//   `#[serde(remote = "Self")]` and implement `Deserialize` with this function,
+//   which falls back to `other` for any value the derived impl doesn't recognize.
+//   (Deserialize-only enums instead use a `#[serde(other)]` unit variant).
+// TODO: pub(crate) fn
+pub fn deserialize_lenient<'de, D, T, F>(deserializer: D, known: F, other: fn(String) -> T) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: FnOnce(StrDeserializer<de::value::Error>) -> Result<T, de::value::Error>,
+{
+    let value = String::deserialize(deserializer)?;
+    let result = known(value.as_str().into_deserializer());
+    Ok(result.unwrap_or_else(|_| other(value)))
+}
+
 // NOTE: Only intended to handle conversion from ASCII CamelCase to SnakeCase
 //   This function is used to convert static Rust identifiers to snakecase
 // TODO: pub(crate) fn
@@ -109,4 +243,62 @@ mod tests {
         assert_eq!(to_snakecase("UPPER").as_str(), "upper");
         assert_eq!(to_snakecase("lower").as_str(), "lower");
     }
+
+    #[test]
+    fn list_remaining() {
+        use super::{List, Object};
+        use client::Client;
+        use error::Error;
+        use mock::{ok, paths, serve};
+        use serde_json as json;
+
+        #[derive(Deserialize)]
+        struct Line {
+            id: String,
+        }
+
+        impl Object for Line {
+            fn id(&self) -> &str {
+                &self.id
+            }
+        }
+
+        let (api_base, server) = serve(ok(vec![
+            r#"{"data":[{"id":"il_3"},{"id":"il_4"}],"has_more":true,"url":"/v1/invoices/in_123/lines"}"#.to_string(),
+            r#"{"data":[{"id":"il_5"}],"has_more":false,"url":"/v1/invoices/in_123/lines"}"#.to_string(),
+        ]));
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let embedded: List<Line> = json::from_str(
+            r#"{"data":[{"id":"il_1"},{"id":"il_2"}],"has_more":true,"url":"/v1/invoices/in_123/lines"}"#,
+        ).unwrap();
+        let rest = embedded.remaining(&client, 100).unwrap();
+        let ids: Vec<_> = rest.iter().map(|line| line.id.as_str()).collect();
+        assert_eq!(ids, vec!["il_3", "il_4", "il_5"]);
+        assert_eq!(paths(&server.join().unwrap()), vec![
+            "/v1/invoices/in_123/lines?limit=100&starting_after=il_2",
+            "/v1/invoices/in_123/lines?limit=100&starting_after=il_4",
+        ]);
+
+        // A list's url can include a query (eg. a subscription's items)
+        let (api_base, server) = serve(ok(vec![
+            r#"{"data":[{"id":"si_2"}],"has_more":false,"url":"/v1/subscription_items?subscription=sub_123"}"#.to_string(),
+        ]));
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let items: List<Line> = json::from_str(
+            r#"{"data":[{"id":"si_1"}],"has_more":true,"url":"/v1/subscription_items?subscription=sub_123"}"#,
+        ).unwrap();
+        assert_eq!(items.remaining(&client, 100).unwrap().len(), 1);
+        assert_eq!(paths(&server.join().unwrap()), vec!["/v1/subscription_items?subscription=sub_123&limit=100&starting_after=si_1"]);
+
+        // ... and too many remaining items fail rather than being truncated
+        let (api_base, _server) = serve(ok(vec![
+            r#"{"data":[{"id":"il_3"},{"id":"il_4"}],"has_more":true,"url":"/v1/invoices/in_123/lines"}"#.to_string(),
+        ]));
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        match embedded.remaining(&client, 1) {
+            Err(Error::TooManyItems { max_items }) => assert_eq!(max_items, 1),
+            other => panic!("expected too many items, got {:?}", other.map(|items| items.len())),
+        }
+    }
 }
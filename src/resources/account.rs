@@ -1,5 +1,7 @@
- use params::{List, Metadata, Timestamp};
-use resources::BankAccount;
+use error::Error;
+use client::Client;
+use params::{List, Metadata, Timestamp};
+use resources::{Capability, ExternalAccount, ExternalAccountParams};
 use serde_json as json;
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -12,8 +14,10 @@ pub struct DeclineChargeDetails {
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct PayoutScheduleDetails {
-    pub delay_days: u64,
-    pub interval: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>, // (manual, daily, weekly, monthly)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub monthly_anchor: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,19 +34,166 @@ pub struct TOSAcceptanceDetails {
     pub user_agent: Option<String>,
 }
 
+/// Information about an account's business, shown to its customers.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-business_profile.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BusinessProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcc: Option<String>, // (merchant category code)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BrandingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>, // (the id of a file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>, // (the id of a file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_color: Option<String>, // eg. "#ff0000"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_color: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CardPaymentsSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_on: Option<DeclineChargeDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_prefix: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PaymentsSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PayoutSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debit_negative_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<PayoutScheduleDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+}
+
+/// The options for customizing how an account functions within Stripe.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-settings.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AccountSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branding: Option<BrandingSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_payments: Option<CardPaymentsSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments: Option<PaymentsSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payouts: Option<PayoutSettings>,
+}
+
+/// A problem with the information provided for a requirement.
+#[derive(Debug, Deserialize)]
+pub struct RequirementsError {
+    pub code: String,
+    pub reason: String,
+    pub requirement: String,
+}
+
+/// The information which Stripe still needs to enable an account or capability.
+///
+/// For more details see https://stripe.com/docs/api/accounts/object#account_object-requirements.
+#[derive(Debug, Default, Deserialize)]
+pub struct Requirements {
+    pub current_deadline: Option<Timestamp>,
+    #[serde(default)]
+    pub currently_due: Vec<String>,
+    pub disabled_reason: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<RequirementsError>,
+    #[serde(default)]
+    pub eventually_due: Vec<String>,
+    #[serde(default)]
+    pub past_due: Vec<String>,
+    #[serde(default)]
+    pub pending_verification: Vec<String>,
+}
+
+/// A single-use link which logs an Express account's user into their Stripe dashboard.
+///
+/// For more details see https://stripe.com/docs/api/account/login_link.
+#[derive(Debug, Deserialize)]
+pub struct LoginLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub url: String,
+}
+
 /// The set of parameters that can be used when creating an account for users.
 ///
 /// For more details see https://stripe.com/docs/api#create_account.
 #[derive(Serialize)]
 pub struct AccountParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_profile: Option<BusinessProfile>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<&'a str>, // (country the account holder resides in)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>, // (required if account type is standard)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<AccountSettings>,
     #[serde(rename = "type")]
     pub account_type: &'static str,
 }
 
+/// The set of parameters that can be used when updating an account.
+///
+/// For more details see https://stripe.com/docs/api/accounts/update.
+#[derive(Default, Serialize)]
+pub struct AccountUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_profile: Option<BusinessProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<AccountSettings>,
+}
+
+/// The reason for rejecting a connected account.
+///
+/// For more details see https://stripe.com/docs/api/account/reject#reject_account-reason.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountRejectReason {
+    Fraud,
+    TermsOfService,
+    Other,
+}
+
+/// The set of parameters that can be used when rejecting an account.
+///
+/// For more details see https://stripe.com/docs/api/account/reject.
+#[derive(Serialize)]
+pub struct AccountRejectParams {
+    pub reason: AccountRejectReason,
+}
+
 /// The resource representing a Stripe account.
 ///
 /// For more details see https://stripe.com/docs/api#account.
@@ -51,6 +202,8 @@ pub struct Account {
     pub id: String,
     pub object: String,
     pub business_name: String,
+    #[serde(default)]
+    pub business_profile: Option<BusinessProfile>,
     pub business_url: Option<String>,
     pub charges_enabed: bool,
     pub country: String,
@@ -60,13 +213,19 @@ pub struct Account {
     pub details_submitted: bool,
     pub display_name: String,
     pub email: String,
-    pub external_accounts: List<BankAccount>,
+    pub external_accounts: List<ExternalAccount>,
     pub legal_entity: Option<json::Value>,
+    #[serde(default)]
+    pub livemode: Option<bool>, // not included by every API version
     pub metadata: Metadata,
     pub payout_schedule: Option<PayoutScheduleDetails>,
     pub payout_statement_descriptor: Option<String>,
     pub payouts_enabled: bool,
     pub product_description: Option<String>,
+    #[serde(default)]
+    pub requirements: Option<Requirements>,
+    #[serde(default)]
+    pub settings: Option<AccountSettings>,
     pub statement_descriptor: String,
     pub support_email: String,
     pub support_phone: String,
@@ -76,3 +235,56 @@ pub struct Account {
     pub account_type: Option<String>, // (Stripe, Custom, or Express)
     pub verification: Option<json::Value>,
 }
+
+impl Account {
+    /// Updates a connected account's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/accounts/update.
+    pub fn update(client: &Client, account_id: &str, params: AccountUpdateParams) -> Result<Account, Error> {
+        client.post(&Client::path_for(&["accounts", account_id]), params)
+    }
+
+    /// Sets how often a connected account's balance is paid out (eg. `interval: "manual"`).
+    ///
+    /// For more details see https://stripe.com/docs/connect/manage-payout-schedule.
+    pub fn set_payout_schedule(client: &Client, account_id: &str, schedule: PayoutScheduleDetails) -> Result<Account, Error> {
+        let params = AccountUpdateParams {
+            settings: Some(AccountSettings {
+                payouts: Some(PayoutSettings { schedule: Some(schedule), ..Default::default() }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        Account::update(client, account_id, params)
+    }
+
+    /// Creates a login link to the Express dashboard of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/account/create_login_link.
+    pub fn create_login_link(client: &Client, account_id: &str) -> Result<LoginLink, Error> {
+        client.post_empty(&Client::path_for(&["accounts", account_id, "login_links"]))
+    }
+
+    /// Rejects a connected account, eg. after detecting fraud on it.
+    ///
+    /// **This cannot be undone**: a rejected account is permanently deactivated.
+    ///
+    /// For more details see https://stripe.com/docs/api/account/reject.
+    pub fn reject(client: &Client, account_id: &str, params: AccountRejectParams) -> Result<Account, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "reject"]), params)
+    }
+
+    /// Creates a bank account or debit card which a connected account can be paid out to.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create.
+    pub fn create_external_account(client: &Client, account_id: &str, params: ExternalAccountParams) -> Result<ExternalAccount, Error> {
+        ExternalAccount::create(client, account_id, params)
+    }
+
+    /// Lists all capabilities of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/list.
+    pub fn retrieve_capabilities(client: &Client, account_id: &str) -> Result<List<Capability>, Error> {
+        Capability::list(client, account_id)
+    }
+}
@@ -1,9 +1,35 @@
-#[derive(Debug, Deserialize, Serialize)]
+/// An address, as used by customers, charges, payment methods, and sources.
+///
+/// For more details see https://stripe.com/docs/api#address_object.
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Address {
-    pub line1: String,
-    pub line2: String,
-    pub city: String,
-    pub state: String,
-    pub postal_code: String,
-    pub country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>, // eg. "US"
+}
+
+/// Shipping information, as used by customers, charges, orders, and payment intents.
+///
+/// For more details see https://stripe.com/docs/api#shipping_object.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Shipping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>, // eg. Fedex, UPS, USPS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
 }
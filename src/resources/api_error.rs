@@ -0,0 +1,20 @@
+use error::{DeclineCode, ErrorCode, ErrorType};
+use params::Expandable;
+use resources::PaymentMethod;
+
+/// An error embedded in a resource, eg. the one which caused a payment intent's last payment
+/// attempt or an invoice's finalization to fail.
+///
+/// For more details see https://stripe.com/docs/api/errors.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    #[serde(rename = "type")]
+    pub error_type: ErrorType,
+    pub charge: Option<String>,
+    pub code: Option<ErrorCode>,
+    pub decline_code: Option<DeclineCode>,
+    pub doc_url: Option<String>,
+    pub message: Option<String>,
+    pub param: Option<String>,
+    pub payment_method: Option<Expandable<PaymentMethod>>,
+}
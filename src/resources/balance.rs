@@ -1,17 +1,39 @@
+use error::Error;
+use client::Client;
 use serde_json as json;
-use params::{List, Timestamp};
+use params::{List, ListParams, Object, RangeQuery, Timestamp};
 use resources::{Currency, Source};
+use serde_qs as qs;
 
+/// The kind of a fee on a balance transaction.
+///
+/// For more details see https://stripe.com/docs/api#balance_transaction_object-fee_details-type.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeType {
+    ApplicationFee,
+    StripeFee,
+    Tax,
+    #[serde(other)]
+    Other,
+}
+
+/// A fee included in a balance transaction's `fee`.
+///
+/// For more details see https://stripe.com/docs/api#balance_transaction_object-fee_details.
 #[derive(Debug, Deserialize)]
-pub struct FeeDetails {
-    pub amount: u64,
-    pub application: String,
+pub struct FeeDetail {
+    pub amount: i64,
+    pub application: Option<String>,
     pub currency: Currency,
-    pub description: String,
+    pub description: Option<String>,
     #[serde(rename = "type")]
-    pub fee_type: String, // (application_fee, stripe_fee, or tax)
+    pub fee_type: FeeType,
 }
 
+#[deprecated(note = "renamed to FeeDetail")]
+pub type FeeDetails = FeeDetail;
+
 /// The resource representing a Stripe account balance.
 ///
 /// For more details see https://stripe.com/docs/api#balance_object.
@@ -31,16 +53,109 @@ pub struct Balance {
 pub struct BalanceTransaction {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub available_on: Timestamp,
     pub created: Timestamp,
     pub currency: Currency,
-    pub description: String,
-    pub fee: u64,
-    pub fee_details: List<FeeDetails>,
-    pub net: u64,
+    pub description: Option<String>,
+    pub fee: i64,
+    pub fee_details: Vec<FeeDetail>,
+    pub net: i64,
     pub source: Source,
     pub status: String,
     #[serde(rename = "type")]
     pub transaction_type: String,
 }
+
+/// The set of parameters that can be used when listing balance transactions.
+///
+/// For more details see https://stripe.com/docs/api/balance_transactions/list.
+#[derive(Default, Serialize)]
+pub struct BalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<&'a str>,
+}
+
+impl<'a> ListParams for BalanceTransactionListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+impl BalanceTransaction {
+    /// Retrieves the details of a balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance_transactions/retrieve.
+    pub fn retrieve(client: &Client, transaction_id: &str) -> Result<BalanceTransaction, Error> {
+        client.get_at(&["balance_transactions", transaction_id])
+    }
+
+    /// List balance transactions.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance_transactions/list.
+    pub fn list(client: &Client, params: BalanceTransactionListParams) -> Result<List<BalanceTransaction>, Error> {
+        client.get(&format!("/balance_transactions?{}", qs::to_string(&params)?))
+    }
+
+    /// Lists all balance transactions, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: BalanceTransactionListParams, max_items: usize) -> Result<Vec<BalanceTransaction>, Error> {
+        client.list_all("/balance_transactions", params, max_items)
+    }
+
+    /// Returns the total of the Stripe fees in the transaction's currency.
+    ///
+    /// Fees in other currencies are not included (see `foreign_fee_details`).
+    pub fn stripe_fee(&self) -> i64 {
+        self.sum_fees(FeeType::StripeFee)
+    }
+
+    /// Returns the total of the application fees in the transaction's currency.
+    ///
+    /// Fees in other currencies are not included (see `foreign_fee_details`).
+    pub fn application_fee(&self) -> i64 {
+        self.sum_fees(FeeType::ApplicationFee)
+    }
+
+    /// Returns the fees charged in a different currency than the transaction's.
+    pub fn foreign_fee_details(&self) -> Vec<&FeeDetail> {
+        self.fee_details.iter().filter(|fee| fee.currency != self.currency).collect()
+    }
+
+    /// Returns true if the transaction's net amount is its amount less its fee.
+    pub fn net_matches(&self) -> bool {
+        self.amount - self.fee == self.net
+    }
+
+    fn sum_fees(&self, fee_type: FeeType) -> i64 {
+        self.fee_details
+            .iter()
+            .filter(|fee| fee.fee_type == fee_type && fee.currency == self.currency)
+            .map(|fee| fee.amount)
+            .sum()
+    }
+}
+
+impl Object for BalanceTransaction {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
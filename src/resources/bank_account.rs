@@ -1,6 +1,16 @@
+use error::Error;
+use client::Client;
 use params::Metadata;
 use resources::Currency;
 
+/// The set of parameters that can be used when verifying a bank account.
+///
+/// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+#[derive(Serialize)]
+pub struct BankAccountVerifyParams {
+    pub amounts: Vec<i64>, // the two micro-deposit amounts, in cents
+}
+
 /// The resource representing a Stripe bank account.
 ///
 /// For more details see https://stripe.com/docs/api#customer_bank_account_object.
@@ -8,17 +18,28 @@ use resources::Currency;
 pub struct BankAccount {
     pub id: String,
     pub object: String,
-    pub account: String,
-    pub account_holder_name: String,
-    pub account_holder_type: String, // (individual or company)
-    pub bank_name: String,
+    pub account: Option<String>, // (only present for accounts attached to a connected account)
+    pub account_holder_name: Option<String>,
+    pub account_holder_type: Option<String>, // (individual or company)
+    pub bank_name: Option<String>,
     pub country: String,
     pub currency: Currency,
-    pub customer: String,
-    pub default_for_currency: bool,
+    pub customer: Option<String>, // (only present for accounts attached to a customer)
+    pub default_for_currency: Option<bool>,
     pub fingerprint: String,
     pub last4: String,
     pub metadata: Metadata,
-    pub routing_number: String,
+    pub routing_number: Option<String>,
     pub status: String, // (new, validated, verified, verification_failed, errored)
 }
+
+impl BankAccount {
+    /// Verifies a customer's bank account using the amounts of the two micro-deposits
+    /// Stripe sent to it.
+    ///
+    /// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+    pub fn verify(client: &Client, customer_id: &str, bank_account_id: &str, amounts: [i64; 2]) -> Result<BankAccount, Error> {
+        let params = BankAccountVerifyParams { amounts: amounts.to_vec() };
+        client.post(&Client::path_for(&["customers", customer_id, "sources", bank_account_id, "verify"]), params)
+    }
+}
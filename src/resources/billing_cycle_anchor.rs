@@ -0,0 +1,25 @@
+use params::Timestamp;
+use serde::{Serialize, Serializer};
+
+/// Where a billing cycle should be anchored, as set by a `billing_cycle_anchor` param.
+///
+/// When creating a subscription this may be a future timestamp; when updating it,
+/// `Now` resets the billing cycle and `Unchanged` keeps it.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/billing-cycle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BillingCycleAnchor {
+    Now,
+    Unchanged,
+    Timestamp(Timestamp),
+}
+
+impl Serialize for BillingCycleAnchor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            BillingCycleAnchor::Now => serializer.serialize_str("now"),
+            BillingCycleAnchor::Unchanged => serializer.serialize_str("unchanged"),
+            BillingCycleAnchor::Timestamp(timestamp) => serializer.serialize_i64(timestamp),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use error::Error;
+use client::Client;
+use params::{List, Timestamp};
+use resources::Requirements;
+
+/// The status of a connected account's capability.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityStatus {
+    Active,
+    Disabled,
+    Inactive,
+    Pending,
+    Unrequested,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when updating a capability.
+///
+/// For more details see https://stripe.com/docs/api/capabilities/update.
+#[derive(Default, Serialize)]
+pub struct CapabilityUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested: Option<bool>,
+}
+
+/// The resource representing a capability of a connected account (eg. card_payments or transfers).
+///
+/// For more details see https://stripe.com/docs/api/capabilities/object.
+#[derive(Debug, Deserialize)]
+pub struct Capability {
+    pub id: String,
+    pub object: String,
+    pub account: String,
+    pub requested: bool,
+    pub requested_at: Option<Timestamp>,
+    #[serde(default)]
+    pub requirements: Option<Requirements>,
+    pub status: CapabilityStatus,
+}
+
+impl Capability {
+    /// Retrieves a capability of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, capability_id: &str) -> Result<Capability, Error> {
+        client.get_at(&["accounts", account_id, "capabilities", capability_id])
+    }
+
+    /// Requests or removes a capability of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/update.
+    pub fn update(client: &Client, account_id: &str, capability_id: &str, params: CapabilityUpdateParams) -> Result<Capability, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "capabilities", capability_id]), params)
+    }
+
+    /// Lists all capabilities of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/capabilities/list.
+    pub fn list(client: &Client, account_id: &str) -> Result<List<Capability>, Error> {
+        client.get_at(&["accounts", account_id, "capabilities"])
+    }
+}
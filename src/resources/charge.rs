@@ -1,42 +1,129 @@
 use client::Client;
 use error::{Error, ErrorCode};
-use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, Currency, CustomerSource, Refund, Source};
+use params::{deserialize_lenient, Expandable, Linked, List, ListParams, Metadata, Object, RangeQuery, Timestamp};
+use resources::{Currency, CustomerSource, Invoice, PaymentIntent, Refund, Shipping, Source, Transfer};
+use resources::{TransferData, TransferDataParams, TransferDataUpdateParams};
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json as json;
 use serde_qs as qs;
 
+/// The network status of a charge's outcome.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-outcome-network_status.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkStatus {
+    ApprovedByNetwork,
+    DeclinedByNetwork,
+    NotSentToNetwork,
+    ReversedAfterApproval,
+    #[serde(other)]
+    Other,
+}
+
+/// The risk level Stripe Radar assessed for a charge.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-outcome-risk_level.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    Normal,
+    Elevated,
+    Highest,
+    NotAssessed,
+    Unknown,
+    #[serde(other)]
+    Other,
+}
+
+/// The kind of outcome of a charge.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-outcome-type.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeType {
+    Authorized,
+    Blocked,
+    Invalid,
+    IssuerDeclined,
+    ManualReview,
+    #[serde(other)]
+    Other,
+}
+
+/// The outcome of a charge, as assessed by Stripe and the card network.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-outcome.
 #[derive(Debug, Deserialize)]
 pub struct ChargeOutcome {
     #[serde(rename = "type")]
-    pub outcome_type: String, // (authorized, manual_review, issuer_declined, blocked, invalid)
-    pub network_status: String, // (approved_by_network, declined_by_network, not_sent_to_network, reversed_after_approval)
+    pub outcome_type: OutcomeType,
+    pub network_status: Option<NetworkStatus>,
     #[serde(default)]
     pub reason: Option<String>,
     #[serde(default)]
-    pub risk_level: Option<String>, // (normal, elevated, highest, not_assessed, unknown)
+    pub risk_level: Option<RiskLevel>,
+    #[serde(default)]
+    pub risk_score: Option<u64>, // (0-100)
     #[serde(default)]
     pub seller_message: Option<String>,
     #[serde(default)]
-    pub rule: Option<String>,
+    pub rule: Option<Expandable<Rule>>,
 }
 
+/// The Radar rule which caused a charge to be blocked or reviewed.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-outcome-rule.
+#[derive(Debug, Deserialize)]
+pub struct Rule {
+    pub id: String,
+    pub action: String, // (allow, block, review, ..)
+    pub predicate: String,
+}
+
+/// A report of whether a charge was fraudulent.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum FraudReport {
+    Fraudulent,
+    Safe,
+
+    /// A report not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for FraudReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, FraudReport::deserialize, FraudReport::Other)
+    }
+}
+
+impl Serialize for FraudReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            FraudReport::Other(ref value) => serializer.serialize_str(value),
+            _ => FraudReport::serialize(self, serializer),
+        }
+    }
+}
+
+/// Fraud reports made about a charge by the seller and by Stripe.
+///
+/// To report a charge as fraudulent (or safe), set `user_report` when updating the charge.
+///
+/// For more details see https://stripe.com/docs/api#charge_object-fraud_details.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct FraudDetails {
-    pub user_report: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_report: Option<FraudReport>,
     #[serde(skip_serializing)]
-    pub stripe_report: Option<String>,
+    pub stripe_report: Option<FraudReport>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ShippingDetails {
-    pub name: String,
-    pub address: Address,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub carrier: Option<String>, // eg. Fedex, UPS, USPS
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tracking_number: Option<String>,
-}
+#[deprecated(note = "replaced by Shipping")]
+pub type ShippingDetails = Shipping;
 
 /// The set of parameters that can be used when capturing a charge.
 ///
@@ -51,6 +138,8 @@ pub struct CaptureParams<'a> {
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataUpdateParams>,
 }
 
 #[derive(Serialize)]
@@ -59,7 +148,6 @@ pub struct DestinationParams<'a> {
     pub amount: u64,
 }
 
-
 /// The set of parameters that can be used when creating or updating a charge.
 ///
 /// For more details see https://stripe.com/docs/api#create_charge and https://stripe.com/docs/api#update_charge.
@@ -72,6 +160,8 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub capture: Option<bool>, // NOTE: if None, Stripe assumes true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -80,6 +170,8 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fraud_details: Option<FraudDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_group: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<&'a str>,
@@ -88,7 +180,7 @@ pub struct ChargeParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<ShippingDetails>,
+    pub shipping: Option<Shipping>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,6 +236,8 @@ pub struct ChargeListParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<SourceFilter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<&'a str>,
@@ -151,6 +245,138 @@ pub struct ChargeListParams<'a> {
     pub transfer_group: Option<&'a str>,
 }
 
+impl<'a> ListParams for ChargeListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit as i64));
+    }
+}
+
+/// The result of 3D Secure authentication for a card charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card-three_d_secure.
+#[derive(Debug, Default, Deserialize)]
+pub struct ThreeDSecureDetails {
+    #[serde(default)]
+    pub authenticated: Option<bool>,
+    #[serde(default)]
+    pub result: Option<String>, // (authenticated, attempt_acknowledged, not_supported, failed, processing_error, ..)
+    #[serde(default)]
+    pub succeeded: Option<bool>,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// The wallet (eg. Apple Pay) a card charge was paid with.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card-wallet.
+#[derive(Debug, Deserialize)]
+pub struct WalletDetails {
+    #[serde(rename = "type")]
+    pub wallet_type: String, // (apple_pay, google_pay, samsung_pay, masterpass, visa_checkout, ..)
+    #[serde(default)]
+    pub dynamic_last4: Option<String>,
+}
+
+/// The card details of a charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-card.
+#[derive(Debug, Deserialize)]
+pub struct ChargeCardDetails {
+    pub brand: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub exp_month: Option<u32>,
+    #[serde(default)]
+    pub exp_year: Option<u32>,
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub funding: Option<String>,
+    pub last4: Option<String>,
+    #[serde(default)]
+    pub network: Option<String>,
+    #[serde(default)]
+    pub three_d_secure: Option<ThreeDSecureDetails>,
+    #[serde(default)]
+    pub wallet: Option<WalletDetails>,
+}
+
+/// The SEPA Direct Debit details of a charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-sepa_debit.
+#[derive(Debug, Deserialize)]
+pub struct SepaDebitDetails {
+    #[serde(default)]
+    pub bank_code: Option<String>,
+    #[serde(default)]
+    pub branch_code: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub last4: Option<String>,
+    #[serde(default)]
+    pub mandate: Option<String>,
+}
+
+/// The ACH Direct Debit details of a charge.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details-ach_debit.
+#[derive(Debug, Deserialize)]
+pub struct AchDebitDetails {
+    #[serde(default)]
+    pub account_holder_type: Option<String>, // (individual, company)
+    #[serde(default)]
+    pub bank_name: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub last4: Option<String>,
+    #[serde(default)]
+    pub routing_number: Option<String>,
+}
+
+/// The details of the payment method a charge was paid with, keyed by its type.
+///
+/// For more details see https://stripe.com/docs/api/charges/object#charge_object-payment_method_details.
+#[derive(Debug)]
+pub enum PaymentMethodDetails {
+    AchDebit(AchDebitDetails),
+    Card(ChargeCardDetails),
+    SepaDebit(SepaDebitDetails),
+
+    /// The type and details of a payment method not yet known to this crate
+    Other(String, json::Value),
+}
+
+impl<'de> Deserialize<'de> for PaymentMethodDetails {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // NOTE: Stripe nests the details under a key matching the type,
+        //   eg. `{"type": "card", "card": {..}}`
+        let value = json::Value::deserialize(deserializer)?;
+        let method_type = match value.get("type").and_then(|method_type| method_type.as_str()) {
+            Some(method_type) => method_type.to_string(),
+            None => return Err(de::Error::missing_field("type")),
+        };
+        let details = value.get(&method_type).cloned().unwrap_or(json::Value::Null);
+        let known = match method_type.as_str() {
+            "ach_debit" => json::from_value(details).map(PaymentMethodDetails::AchDebit),
+            "card" => json::from_value(details).map(PaymentMethodDetails::Card),
+            "sepa_debit" => json::from_value(details).map(PaymentMethodDetails::SepaDebit),
+            _ => return Ok(PaymentMethodDetails::Other(method_type, details)),
+        };
+        known.map_err(de::Error::custom)
+    }
+}
+
 /// The resource representing a Stripe charge.
 ///
 /// For more details see https://stripe.com/docs/api#charges.
@@ -161,6 +387,7 @@ pub struct Charge {
     pub amount_refunded: u64,
     pub application: Option<String>,
     pub application_fee: Option<String>,
+    pub application_fee_amount: Option<u64>,
     pub balance_transaction: Option<String>,
     pub captured: bool,
     pub created: Timestamp,
@@ -172,22 +399,30 @@ pub struct Charge {
     pub failure_code: Option<ErrorCode>,
     pub failure_message: Option<String>,
     pub fraud_details: FraudDetails,
-    pub invoice: Option<String>,
+    pub invoice: Option<Expandable<Invoice>>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub on_behalf_of: Option<String>,
     pub order: Option<String>,
     pub outcome: Option<ChargeOutcome>,
     pub paid: bool,
+    #[serde(default)]
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
+    #[serde(default)]
+    pub payment_method_details: Option<PaymentMethodDetails>,
     pub receipt_email: Option<String>,
     pub receipt_number: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
-    pub shipping: Option<ShippingDetails>,
+    pub shipping: Option<Shipping>,
     pub source: Source,
     pub source_transfer: Option<String>,
     pub statement_descriptor: Option<String>,
     pub status: String, // (succeeded, pending, failed)
+    #[serde(default)]
+    pub transfer: Option<Expandable<Transfer>>,
+    #[serde(default)]
+    pub transfer_data: Option<TransferData>,
     pub transfer_group: Option<String>,
 }
 
@@ -203,27 +438,97 @@ impl Charge {
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_charge.
     pub fn retrieve(client: &Client, charge_id: &str) -> Result<Charge, Error> {
-        client.get(&format!("/charges/{}", charge_id))
+        client.get_at(&["charges", charge_id])
+    }
+
+    /// Retrieves the details of many charges concurrently, with at most `concurrency` requests in flight.
+    ///
+    /// The results are returned in the same order as the ids.
+    pub fn retrieve_many(client: &Client, charge_ids: &[&str], concurrency: usize) -> Vec<Result<Charge, Error>> {
+        let paths = charge_ids.iter().map(|id| Client::path_for(&["charges", *id])).collect();
+        client.get_many(paths, concurrency)
     }
 
     /// Updates a charge's properties.
     ///
     /// For more details see https://stripe.com/docs/api#update_charge.
     pub fn update(client: &Client, charge_id: &str, params: ChargeParams) -> Result<Charge, Error> {
-        client.post(&format!("/charges/{}", charge_id), params)
+        client.post(&Client::path_for(&["charges", charge_id]), params)
     }
 
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see https://stripe.com/docs/api#charge_capture.
     pub fn capture(client: &Client, charge_id: &str, params: CaptureParams) -> Result<Charge, Error> {
-        client.post(&format!("/charges/{}/capture", charge_id), params)
+        client.post(&Client::path_for(&["charges", charge_id, "capture"]), params)
     }
 
     /// List all charges.
     ///
     /// For more details see https://stripe.com/docs/api#list_charges.
-    pub fn list(client: &Client, params: ChargeListParams) -> Result<Vec<Charge>, Error> {
+    pub fn list(client: &Client, params: ChargeListParams) -> Result<List<Charge>, Error> {
         client.get(&format!("/charges?{}", qs::to_string(&params)?))
     }
+
+    /// Lists all charges, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: ChargeListParams, max_items: usize) -> Result<Vec<Charge>, Error> {
+        client.list_all("/charges", params, max_items)
+    }
+
+    /// Returns whether the whole amount of this charge has been refunded.
+    ///
+    /// The charge's `refunds` only include the first page of refunds; the rest can be
+    /// fetched with `charge.refunds.remaining(client, max_items)`.
+    pub fn is_fully_refunded(&self) -> bool {
+        self.amount_refunded >= self.amount
+    }
+
+    /// Returns the invoice this charge pays, fetching it if it wasn't expanded.
+    pub fn invoice(&self, client: &Client) -> Result<Option<Linked<Invoice>>, Error> {
+        match self.invoice {
+            Some(ref invoice) => invoice.get_or_fetch(|id| Invoice::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the payment intent which created this charge, fetching it if it wasn't expanded.
+    pub fn payment_intent(&self, client: &Client) -> Result<Option<Linked<PaymentIntent>>, Error> {
+        match self.payment_intent {
+            Some(ref intent) => intent.get_or_fetch(|id| PaymentIntent::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Object for Charge {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn list_all() {
+        use super::{Charge, ChargeListParams};
+        use client::Client;
+        use mock::{charge, ok, paths, serve};
+
+        let (api_base, server) = serve(ok(vec![
+            format!(r#"{{"data":[{},{}],"has_more":true,"url":"/v1/charges"}}"#, charge("ch_1"), charge("ch_2")),
+            format!(r#"{{"data":[{}],"has_more":false,"url":"/v1/charges"}}"#, charge("ch_3")),
+        ]));
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let mut params = ChargeListParams::default();
+        params.customer = Some("cus_123");
+        params.starting_after = Some("ch_0");
+        let charges = Charge::list_all(&client, params, 10).unwrap();
+        let ids: Vec<_> = charges.iter().map(|charge| charge.id.as_str()).collect();
+        assert_eq!(ids, vec!["ch_1", "ch_2", "ch_3"]);
+        assert_eq!(paths(&server.join().unwrap()), vec![
+            "/v1/charges?customer=cus_123&limit=10&starting_after=ch_0",
+            "/v1/charges?customer=cus_123&limit=10&starting_after=ch_2",
+        ]);
+    }
 }
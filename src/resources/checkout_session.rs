@@ -0,0 +1,260 @@
+use error::Error;
+use client::Client;
+use params::{deserialize_lenient, Expandable, Linked, List, Metadata, Object, Timestamp};
+use resources::{Currency, PaymentIntent, Price, Recurring, TotalDetails};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_qs as qs;
+
+/// The fields of a checkout session which `CheckoutSession::retrieve_expanded` can expand.
+const EXPANDABLE_FIELDS: &'static [&'static str] = &["line_items", "payment_intent"];
+
+/// The product of a line item priced inline.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/create#create_checkout_session-line_items-price_data-product_data.
+#[derive(Default, Serialize)]
+pub struct CheckoutLineItemProductData<'a> {
+    pub name: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The price of a line item, created inline rather than ahead of time.
+///
+/// Either `product` or `product_data` must be given.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/create#create_checkout_session-line_items-price_data.
+#[derive(Serialize)]
+pub struct CheckoutLineItemPriceData<'a> {
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_data: Option<CheckoutLineItemProductData<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<Recurring>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// A line item of a checkout session, with either an existing `price` or an inline `price_data`.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/create#create_checkout_session-line_items.
+#[derive(Default, Serialize)]
+pub struct CheckoutSessionLineItem<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<CheckoutLineItemPriceData<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+/// The set of parameters that can be used when creating a checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/create.
+#[derive(Default, Serialize)]
+pub struct CheckoutSessionParams<'a> {
+    pub success_url: &'a str, // this is a required param
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<CheckoutSessionLineItem<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<&'a str>, // (payment, setup, subscription)
+}
+
+/// An item purchased in a checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/line_items.
+#[derive(Debug, Deserialize)]
+pub struct CheckoutSessionItem {
+    pub id: String,
+    pub amount_subtotal: i64,
+    pub amount_total: i64,
+    pub currency: Currency,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub price: Option<Price>,
+    pub quantity: Option<u64>,
+}
+
+impl Object for CheckoutSessionItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Whether the payment of a checkout session was received.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object#checkout_session_object-payment_status.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum CheckoutSessionPaymentStatus {
+    Paid,
+    Unpaid,
+    NoPaymentRequired,
+
+    /// A payment status not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for CheckoutSessionPaymentStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, CheckoutSessionPaymentStatus::deserialize, CheckoutSessionPaymentStatus::Other)
+    }
+}
+
+impl Serialize for CheckoutSessionPaymentStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            CheckoutSessionPaymentStatus::Other(ref value) => serializer.serialize_str(value),
+            _ => CheckoutSessionPaymentStatus::serialize(self, serializer),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExpandParams<'a> {
+    expand: &'a [&'a str],
+}
+
+/// The resource representing a Stripe Checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object.
+#[derive(Debug, Deserialize)]
+pub struct CheckoutSession {
+    pub id: String,
+    pub object: String,
+    pub amount_total: Option<i64>,
+    pub cancel_url: Option<String>,
+    pub client_reference_id: Option<String>,
+    pub currency: Option<Currency>,
+    pub customer: Option<String>,
+    pub expires_at: Option<Timestamp>,
+    #[serde(default)]
+    pub line_items: Option<List<CheckoutSessionItem>>, // only present when expanded
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub mode: String, // (payment, setup, subscription)
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
+    pub payment_status: CheckoutSessionPaymentStatus,
+    pub status: Option<String>, // (open, complete, expired)
+    pub subscription: Option<String>,
+    pub success_url: Option<String>,
+    #[serde(default)]
+    pub total_details: Option<TotalDetails>,
+    pub url: Option<String>,
+}
+
+impl CheckoutSession {
+    /// Creates a new checkout session.
+    ///
+    /// For more details see https://stripe.com/docs/api/checkout/sessions/create.
+    pub fn create(client: &Client, params: CheckoutSessionParams) -> Result<CheckoutSession, Error> {
+        client.post("/checkout/sessions", params)
+    }
+
+    /// Retrieves the details of a checkout session.
+    ///
+    /// For more details see https://stripe.com/docs/api/checkout/sessions/retrieve.
+    pub fn retrieve(client: &Client, session_id: &str) -> Result<CheckoutSession, Error> {
+        client.get_at(&["checkout", "sessions", session_id])
+    }
+
+    /// Retrieves the details of a checkout session, expanding the given fields.
+    ///
+    /// Only `line_items` and `payment_intent` can be expanded; other fields fail with
+    /// `Error::Unsupported` without making a request, rather than a response which fails to parse.
+    ///
+    /// For more details see https://stripe.com/docs/api/expanding_objects.
+    pub fn retrieve_expanded(client: &Client, session_id: &str, expand: &[&str]) -> Result<CheckoutSession, Error> {
+        if !expand.iter().all(|field| EXPANDABLE_FIELDS.contains(field)) {
+            return Err(Error::Unsupported("can only expand line_items and payment_intent of a checkout session"));
+        }
+        let params = ExpandParams { expand: expand };
+        client.get(&format!("{}?{}", Client::path_for(&["checkout", "sessions", session_id]), qs::to_string(&params)?))
+    }
+
+    /// Returns whether the session's payment was received (eg. so its order can be fulfilled).
+    pub fn is_paid(&self) -> bool {
+        self.payment_status == CheckoutSessionPaymentStatus::Paid
+    }
+
+    /// Returns the total amount of the session, after discounts and taxes.
+    pub fn amount_total(&self) -> Option<i64> {
+        self.amount_total
+    }
+
+    /// Returns the payment intent for this session, fetching it if it wasn't expanded.
+    pub fn payment_intent(&self, client: &Client) -> Result<Option<Linked<PaymentIntent>>, Error> {
+        match self.payment_intent {
+            Some(ref intent) => intent.get_or_fetch(|id| PaymentIntent::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn retrieve_expanded() {
+        use super::CheckoutSession;
+        use client::Client;
+        use mock::{ok, paths, serve};
+
+        let (api_base, server) = serve(ok(vec![r#"{
+            "id": "cs_test_123", "object": "checkout.session", "amount_total": 2000, "livemode": false,
+            "metadata": {}, "mode": "payment", "payment_status": "unpaid", "payment_intent": "pi_123"
+        }"#.to_string()]));
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let session = CheckoutSession::retrieve_expanded(&client, "cs_test_123", &["payment_intent", "line_items"]).unwrap();
+        assert!(!session.is_paid());
+
+        let requests = server.join().unwrap();
+        let path = paths(&requests)[0];
+        assert!(path.starts_with("/v1/checkout/sessions/cs_test_123?expand"));
+        assert!(path.contains("payment_intent") && path.contains("line_items"));
+    }
+
+    #[test]
+    fn retrieve_unknown_expansion() {
+        use super::CheckoutSession;
+        use client::Client;
+        use error::Error;
+        use mock::UNREACHABLE;
+
+        let client = Client::new("sk_test_123").with_api_base(UNREACHABLE);
+        match CheckoutSession::retrieve_expanded(&client, "cs_test_123", &["payment_intent", "customer"]) {
+            Err(Error::Unsupported(_)) => assert_eq!(client.request_count(), 0),
+            other => panic!("expected an unsupported error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn payment_status() {
+        use super::CheckoutSessionPaymentStatus;
+        use serde_json as json;
+
+        let status: CheckoutSessionPaymentStatus = json::from_str(r#""no_payment_required""#).unwrap();
+        assert_eq!(status, CheckoutSessionPaymentStatus::NoPaymentRequired);
+        let status: CheckoutSessionPaymentStatus = json::from_str(r#""partially_paid""#).unwrap();
+        assert_eq!(status, CheckoutSessionPaymentStatus::Other("partially_paid".to_string()));
+    }
+}
@@ -0,0 +1,76 @@
+use error::Error;
+use client::Client;
+use params::{Object, Timestamp};
+use resources::{BillingDetails, PaymentMethodDetails, Shipping};
+
+/// The details of the payment method a confirmation token will create.
+///
+/// For more details see https://stripe.com/docs/api/confirmation_tokens/object#confirmation_token_object-payment_method_preview.
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethodPreview {
+    pub billing_details: BillingDetails,
+    #[serde(default)]
+    pub customer: Option<String>,
+
+    /// The type of the payment method, with its type-specific details (eg. a card's brand and last4).
+    #[serde(flatten)]
+    pub details: PaymentMethodDetails,
+}
+
+/// The card-specific options of a confirmation token.
+#[derive(Debug, Deserialize)]
+pub struct ConfirmationTokenCardOptions {
+    #[serde(default)]
+    pub cvc_token: Option<String>,
+}
+
+/// The payment-method-specific options of a confirmation token.
+///
+/// For more details see https://stripe.com/docs/api/confirmation_tokens/object#confirmation_token_object-payment_method_options.
+#[derive(Debug, Deserialize)]
+pub struct ConfirmationTokenPaymentMethodOptions {
+    #[serde(default)]
+    pub card: Option<ConfirmationTokenCardOptions>,
+}
+
+/// The resource representing a Stripe confirmation token.
+///
+/// Confirmation tokens are created client-side (eg. by the Payment Element), and
+/// can be retrieved to inspect the payment details before confirming an intent.
+///
+/// For more details see https://stripe.com/docs/api/confirmation_tokens/object.
+#[derive(Debug, Deserialize)]
+pub struct ConfirmationToken {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub expires_at: Option<Timestamp>,
+    pub livemode: bool,
+    #[serde(default)]
+    pub payment_intent: Option<String>,
+    #[serde(default)]
+    pub payment_method_options: Option<ConfirmationTokenPaymentMethodOptions>,
+    #[serde(default)]
+    pub payment_method_preview: Option<PaymentMethodPreview>,
+    pub return_url: Option<String>,
+    pub setup_future_usage: Option<String>, // (on_session, off_session)
+    #[serde(default)]
+    pub setup_intent: Option<String>,
+    pub shipping: Option<Shipping>,
+    pub use_stripe_sdk: bool,
+}
+
+impl ConfirmationToken {
+    /// Retrieves the details of a confirmation token.
+    ///
+    /// For more details see https://stripe.com/docs/api/confirmation_tokens/retrieve.
+    pub fn retrieve(client: &Client, confirmation_token_id: &str) -> Result<ConfirmationToken, Error> {
+        client.get_at(&["confirmation_tokens", confirmation_token_id])
+    }
+}
+
+impl Object for ConfirmationToken {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,199 @@
+use error::Error;
+use client::Client;
+use resources::{Charge, ChargeParams, Transfer, TransferListParams, TransferParams, TransferReversalParams};
+use std::error;
+use std::fmt;
+
+/// Helpers for Stripe Connect flows which take more than one request.
+pub struct Connect;
+
+/// The charge and transfer made by `Connect::charge_and_transfer`.
+#[derive(Debug)]
+pub struct ChargeAndTransfer {
+    pub charge: Charge,
+    pub transfer: Transfer,
+}
+
+/// The error returned by `Connect::charge_and_transfer`.
+#[derive(Debug)]
+pub enum ChargeAndTransferError {
+    /// The customer couldn't be charged, so nothing was transferred.
+    Charge(Error),
+    /// The customer was charged, but the charge couldn't be transferred.
+    ///
+    /// The charge is left as is, so it can be retried or refunded as the caller sees fit.
+    /// `rollback` is the error reversing a transfer made despite `error`, if that failed too.
+    Transfer { charge: Box<Charge>, error: Error, rollback: Option<Error> },
+}
+
+impl fmt::Display for ChargeAndTransferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(error::Error::description(self))?;
+        match *self {
+            ChargeAndTransferError::Charge(ref err) => write!(f, ": {}", err),
+            ChargeAndTransferError::Transfer { ref charge, ref error, rollback: None } => {
+                write!(f, ": {} (charge {} was kept)", error, charge.id)
+            }
+            ChargeAndTransferError::Transfer { ref charge, ref error, rollback: Some(ref rollback) } => {
+                write!(f, ": {} (charge {} was kept, and reversing its transfer failed: {})", error, charge.id, rollback)
+            }
+        }
+    }
+}
+
+impl error::Error for ChargeAndTransferError {
+    fn description(&self) -> &str {
+        match *self {
+            ChargeAndTransferError::Charge(_) => "error charging a customer",
+            ChargeAndTransferError::Transfer { .. } => "error transferring a charge to a connected account",
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        match *self {
+            ChargeAndTransferError::Charge(ref err) => Some(err),
+            ChargeAndTransferError::Transfer { ref error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<ChargeAndTransferError> for Error {
+    fn from(err: ChargeAndTransferError) -> Error {
+        match err {
+            ChargeAndTransferError::Charge(err) => err,
+            ChargeAndTransferError::Transfer { error, rollback: None, .. } => error,
+            ChargeAndTransferError::Transfer { error, rollback: Some(rollback), .. } => {
+                Error::Rollback { error: Box::new(error), rollback: Box::new(rollback) }
+            }
+        }
+    }
+}
+
+impl Connect {
+    /// Charges a customer, then transfers `transfer_amount` of the charge to a connected account.
+    ///
+    /// If the transfer fails, any transfer which was made despite the error is reversed, and
+    /// a `ChargeAndTransferError::Transfer` holding the charge and the transfer's error is returned.
+    /// The charge is not refunded, so it can be retried or refunded by the caller.
+    ///
+    /// For more details see https://stripe.com/docs/connect/charges-transfers.
+    pub fn charge_and_transfer(
+        client: &Client,
+        params: ChargeParams,
+        destination: &str,
+        transfer_amount: u64,
+    ) -> Result<ChargeAndTransfer, ChargeAndTransferError> {
+        let charge = Charge::create(client, params).map_err(ChargeAndTransferError::Charge)?;
+        let transfer_group = charge.transfer_group.clone().unwrap_or_else(|| charge.id.clone());
+        let transfer = Transfer::create(client, TransferParams {
+            amount: transfer_amount,
+            currency: charge.currency.clone(),
+            destination: destination,
+            description: None,
+            metadata: None,
+            source_transaction: Some(&charge.id),
+            transfer_group: Some(&transfer_group),
+        });
+        match transfer {
+            Ok(transfer) => Ok(ChargeAndTransfer { charge: charge, transfer: transfer }),
+            Err(err) => {
+                let rollback = reverse_transfers(client, &charge, &transfer_group, &err).err();
+                Err(ChargeAndTransferError::Transfer { charge: Box::new(charge), error: err, rollback: rollback })
+            }
+        }
+    }
+}
+
+fn reverse_transfers(client: &Client, charge: &Charge, transfer_group: &str, error: &Error) -> Result<(), Error> {
+    // NOTE: A request which Stripe rejected didn't make a transfer, but one which failed
+    //   in transit (eg. a timeout) or on Stripe's side (a 5xx) may have, so reverse any
+    //   transfer made from the charge.
+    match *error {
+        Error::Stripe(ref err) if err.http_status < 500 => Ok(()),
+        Error::Idempotency(_) | Error::Permission(_) => Ok(()),
+        _ => {
+            let params = TransferListParams { transfer_group: Some(transfer_group), ..Default::default() };
+            for transfer in Transfer::list_all(client, params, 100)? {
+                if transfer.source_transaction.as_ref() == Some(&charge.id) {
+                    Transfer::reverse(client, &transfer.id, TransferReversalParams::default())?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn charge_and_transfer_rollback() {
+        use super::{ChargeAndTransferError, Connect};
+        use client::Client;
+        use error::Error;
+        use mock::{charge, requests, serve};
+        use resources::ChargeParams;
+
+        let (api_base, server) = serve(vec![
+            ("200 OK", charge("ch_123")),
+            ("400 Bad Request", r#"{"error":{"type":"invalid_request_error","message":"Insufficient funds"}}"#.to_string()),
+        ]);
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let mut params = ChargeParams::default();
+        params.amount = Some(500);
+        match Connect::charge_and_transfer(&client, params, "acct_123", 400) {
+            Err(ChargeAndTransferError::Transfer { charge, error, rollback: None }) => {
+                assert_eq!(charge.id, "ch_123");
+                match error {
+                    Error::Stripe(ref err) => assert_eq!(err.message.as_ref().map(|msg| msg.as_str()), Some("Insufficient funds")),
+                    ref other => panic!("expected the transfer's error, got {:?}", other),
+                }
+            }
+            other => panic!("expected the charge without its transfer, got {:?}", other),
+        }
+
+        // NOTE: The charge is kept, and the rejected transfer didn't need reversing
+        assert_eq!(requests(&server.join().unwrap()), vec!["POST /v1/charges", "POST /v1/transfers"]);
+    }
+
+    #[test]
+    fn charge_and_transfer_server_error() {
+        use super::{ChargeAndTransferError, Connect};
+        use client::Client;
+        use mock::{charge, requests, serve};
+        use resources::ChargeParams;
+
+        let transfer = |id: &str, source_transaction: &str| format!(r#"{{
+            "id": "{}", "object": "transfer", "amount": 400, "amount_reversed": 0, "balance_transaction": "txn_123",
+            "created": 1500000000, "currency": "usd", "description": null, "destination": "acct_123",
+            "destination_payment": "py_123", "livemode": false, "metadata": {{}},
+            "reversals": {{"data": [], "has_more": false, "url": "/v1/transfers/{}/reversals"}}, "reversed": false,
+            "source_transaction": "{}", "source_type": "card", "transfer_group": "ch_123"
+        }}"#, id, id, source_transaction);
+        let (api_base, server) = serve(vec![
+            ("200 OK", charge("ch_123")),
+            ("500 Internal Server Error", r#"{"error":{"type":"api_error","message":"An unknown error occurred"}}"#.to_string()),
+            ("200 OK", format!(r#"{{"data":[{},{}],"has_more":false,"url":"/v1/transfers"}}"#, transfer("tr_other", "ch_other"), transfer("tr_123", "ch_123"))),
+            ("200 OK", r#"{
+                "id": "trr_123", "object": "transfer_reversal", "amount": 400, "balance_transaction": "txn_456",
+                "created": 1500000000, "currency": "usd", "metadata": {}, "transfer": "tr_123"
+            }"#.to_string()),
+        ]);
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let mut params = ChargeParams::default();
+        params.amount = Some(500);
+        match Connect::charge_and_transfer(&client, params, "acct_123", 400) {
+            Err(ChargeAndTransferError::Transfer { ref charge, rollback: None, .. }) => assert_eq!(charge.id, "ch_123"),
+            other => panic!("expected the charge without its transfer, got {:?}", other),
+        }
+
+        // NOTE: A 5xx may have made the transfer anyway, so the charge's transfers are reversed
+        assert_eq!(requests(&server.join().unwrap()), vec![
+            "POST /v1/charges",
+            "POST /v1/transfers",
+            "GET /v1/transfers?limit=100&transfer_group=ch_123",
+            "POST /v1/transfers/tr_123/reversals",
+        ]);
+    }
+}
@@ -1,10 +1,12 @@
-use params::to_snakecase;
+use params::{deserialize_lenient, to_snakecase};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// Currency is the list of supported currencies.
 ///
 /// For more details see https://support.stripe.com/questions/which-currencies-does-stripe-support.
-#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub enum Currency {
     #[serde(rename = "aed")]
     AED, // United Arab Emirates Dirham
@@ -284,6 +286,25 @@ pub enum Currency {
     ZAR, // South African Rand
     #[serde(rename = "zmw")]
     ZMW, // Zambian Kwacha
+
+    /// A currency not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, Currency::deserialize, Currency::Other)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Currency::Other(ref value) => serializer.serialize_str(value),
+            _ => Currency::serialize(self, serializer),
+        }
+    }
 }
 
 impl Default for Currency {
@@ -294,6 +315,9 @@ impl Default for Currency {
 
 impl fmt::Display for Currency {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+        match *self {
+            Currency::Other(ref value) => write!(f, "{}", value),
+            _ => write!(f, "{}", to_snakecase(&format!("{:?}", self))),
+        }
     }
 }
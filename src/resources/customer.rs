@@ -1,16 +1,31 @@
 use error::Error;
 use client::Client;
-use resources::{Address, CardParams, Currency, Deleted, Discount, Source, Subscription};
-use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{CardParams, Currency, Deleted, Discount, PaymentMethod, PaymentMethodListParams, Shipping, Source, Subscription};
+use params::{List, ListParams, Metadata, Object, RangeQuery, Timestamp};
 use serde_qs as qs;
 
 #[derive(Debug, Deserialize, Serialize)]
-pub struct CustomerShippingDetails {
-    pub address: Address,
+pub struct InvoiceCustomField {
     pub name: String,
-    pub phone: String,
+    pub value: String,
 }
 
+/// The customer's default settings for invoices.
+///
+/// For more details see https://stripe.com/docs/api/customers/object#customer_object-invoice_settings.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<InvoiceCustomField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+}
+
+#[deprecated(note = "replaced by Shipping")]
+pub type CustomerShippingDetails = Shipping;
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum CustomerSource<'a> {
@@ -34,11 +49,15 @@ pub struct CustomerParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<InvoiceSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping: Option<CustomerShippingDetails>,
+    pub shipping: Option<Shipping>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<CustomerSource<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<&'a str>,
 }
 
 /// The set of parameters that can be used when listing customers.
@@ -54,6 +73,26 @@ pub struct CustomerListParams<'a> {
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<&'a str>,
+}
+
+impl<'a> ListParams for CustomerListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit as i64));
+    }
+}
+
+/// A customer's default payment method, as resolved by `Customer::default_payment_method`.
+#[derive(Debug)]
+pub enum DefaultPaymentMethod {
+    PaymentMethod(PaymentMethod),
+    /// The id of a legacy source (eg. a `card_`, `ba_` or `src_` id), which isn't retrieved.
+    Source(String),
 }
 
 /// The resource representing a Stripe customer.
@@ -71,11 +110,15 @@ pub struct Customer {
     pub desc: Option<String>,
     pub discount: Option<Discount>,
     pub email: Option<String>,
+    #[serde(default)]
+    pub invoice_settings: Option<InvoiceSettings>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub shipping: Option<CustomerShippingDetails>,
+    pub shipping: Option<Shipping>,
     pub sources: List<Source>,
     pub subscriptions: List<Subscription>,
+    #[serde(default)]
+    pub test_clock: Option<String>,
 }
 
 impl Customer {
@@ -90,21 +133,21 @@ impl Customer {
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_customer.
     pub fn retrieve(client: &Client, customer_id: &str) -> Result<Customer, Error> {
-        client.get(&format!("/customers/{}", customer_id))
+        client.get_at(&["customers", customer_id])
     }
 
     /// Updates a customer's properties.
     ///
     /// For more details see https://stripe.com/docs/api#update_customer.
     pub fn update(client: &Client, customer_id: &str, params: CustomerParams) -> Result<Customer, Error> {
-        client.post(&format!("/customers/{}", customer_id), params)
+        client.post(&Client::path_for(&["customers", customer_id]), params)
     }
 
     /// Deletes a customer.
     ///
     /// For more details see https://stripe.com/docs/api#delete_customer.
     pub fn delete(client: &Client, customer_id: &str) -> Result<Deleted, Error> {
-        client.delete(&format!("/customers/{}", customer_id))
+        client.delete(&Client::path_for(&["customers", customer_id]))
     }
 
     /// List customers.
@@ -113,4 +156,124 @@ impl Customer {
     pub fn list(client: &Client, params: CustomerListParams) -> Result<List<Customer>, Error> {
         client.get(&format!("/customers?{}", qs::to_string(&params)?))
     }
+
+    /// Attaches a payment method to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/attach.
+    pub fn attach_payment_method(client: &Client, customer_id: &str, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        PaymentMethod::attach(client, payment_method_id, customer_id)
+    }
+
+    /// Detaches a payment method from the customer it is attached to.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/detach.
+    pub fn detach_payment_method(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        PaymentMethod::detach(client, payment_method_id)
+    }
+
+    /// Sets the payment method used by default for a customer's invoices and subscriptions.
+    ///
+    /// The payment method must already be attached to the customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/customers/update#update_customer-invoice_settings.
+    pub fn set_default_payment_method(client: &Client, customer_id: &str, payment_method_id: &str) -> Result<Customer, Error> {
+        let mut params = CustomerParams::default();
+        params.invoice_settings = Some(InvoiceSettings {
+            default_payment_method: Some(payment_method_id.to_string()),
+            ..InvoiceSettings::default()
+        });
+        Customer::update(client, customer_id, params)
+    }
+
+    /// Lists the payment methods attached to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/customer_list.
+    pub fn list_payment_methods(client: &Client, customer_id: &str, params: PaymentMethodListParams) -> Result<List<PaymentMethod>, Error> {
+        client.get(&format!("{}?{}", Client::path_for(&["customers", customer_id, "payment_methods"]), qs::to_string(&params)?))
+    }
+
+    /// Lists all customers, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: CustomerListParams, max_items: usize) -> Result<Vec<Customer>, Error> {
+        client.list_all("/customers", params, max_items)
+    }
+
+    /// Returns the id of the customer's default payment method, if they have one.
+    ///
+    /// Prefers `invoice_settings.default_payment_method`, falling back to the legacy `default_source`.
+    pub fn default_payment_method_id(&self) -> Option<&str> {
+        self.invoice_settings.as_ref()
+            .and_then(|settings| settings.default_payment_method.as_ref())
+            .or(self.default_source.as_ref())
+            .map(|id| id.as_str())
+    }
+
+    /// Retrieves the customer's default payment method, if they have one.
+    ///
+    /// A legacy default source can't be retrieved as a payment method (eg. a `ba_` id),
+    /// so its id is returned as is without making a request.
+    pub fn default_payment_method(&self, client: &Client) -> Result<Option<DefaultPaymentMethod>, Error> {
+        match self.default_payment_method_id() {
+            Some(id) if id.starts_with("pm_") => PaymentMethod::retrieve(client, id).map(|method| Some(DefaultPaymentMethod::PaymentMethod(method))),
+            Some(id) => Ok(Some(DefaultPaymentMethod::Source(id.to_string()))),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Object for Customer {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn default_payment_method() {
+        use super::{Customer, DefaultPaymentMethod};
+        use client::Client;
+        use mock::UNREACHABLE;
+        use mock::{ok, paths, serve};
+        use serde_json as json;
+
+        let customer = |default_source: &str, invoice_settings: &str| -> Customer {
+            json::from_str(&format!(r#"{{
+                "id": "cus_123", "account_balance": 0, "business_vat_id": null, "created": 1500000000,
+                "currency": "usd", "default_source": {}, "delinquent": false, "desc": null, "discount": null,
+                "email": null, "invoice_settings": {}, "livemode": false, "metadata": {{}}, "shipping": null,
+                "sources": {{"data": [], "has_more": false, "url": "/v1/customers/cus_123/sources"}},
+                "subscriptions": {{"data": [], "has_more": false, "url": "/v1/customers/cus_123/subscriptions"}}
+            }}"#, default_source, invoice_settings)).unwrap()
+        };
+
+        let client = Client::new("sk_test_123").with_api_base(UNREACHABLE);
+        match customer(r#""ba_123""#, "null").default_payment_method(&client) {
+            Ok(Some(DefaultPaymentMethod::Source(ref id))) => assert_eq!(id, "ba_123"),
+            other => panic!("expected the legacy source, got {:?}", other),
+        }
+        assert!(customer("null", "null").default_payment_method(&client).unwrap().is_none());
+
+        let (api_base, server) = serve(ok(vec![r#"{
+            "id": "pm_123", "object": "payment_method", "billing_details": {}, "created": 1500000000,
+            "customer": "cus_123", "livemode": false, "metadata": {}, "type": "card"
+        }"#.to_string()]));
+        let client = client.with_api_base(api_base);
+        match customer(r#""card_123""#, r#"{"default_payment_method": "pm_123"}"#).default_payment_method(&client) {
+            Ok(Some(DefaultPaymentMethod::PaymentMethod(ref method))) => assert_eq!(method.id, "pm_123"),
+            other => panic!("expected the payment method, got {:?}", other),
+        }
+        assert_eq!(paths(&server.join().unwrap()), vec!["/v1/payment_methods/pm_123"]);
+    }
+
+    #[test]
+    fn delete_encodes_id() {
+        use super::Customer;
+        use client::Client;
+        use mock::{ok, paths, serve};
+
+        let (api_base, server) = serve(ok(vec![r#"{"id": "cus_123", "deleted": true}"#.to_string()]));
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        assert!(Customer::delete(&client, "cus_123/../charges").unwrap().deleted);
+        assert_eq!(paths(&server.join().unwrap()), vec!["/v1/customers/cus_123%2F..%2Fcharges"]);
+    }
 }
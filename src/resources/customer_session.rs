@@ -0,0 +1,55 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+
+/// Whether an embedded component may use a customer session.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionComponent {
+    pub enabled: bool,
+}
+
+/// The embedded components which a customer session can be used with.
+///
+/// For more details see https://stripe.com/docs/api/customer_sessions/object#customer_session_object-components.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct CustomerSessionComponents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buy_button: Option<CustomerSessionComponent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_element: Option<CustomerSessionComponent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pricing_table: Option<CustomerSessionComponent>,
+}
+
+/// The set of parameters that can be used when creating a customer session.
+///
+/// For more details see https://stripe.com/docs/api/customer_sessions/create.
+#[derive(Default, Serialize)]
+pub struct CustomerSessionCreateParams<'a> {
+    pub customer: &'a str, // this is a required param
+    pub components: CustomerSessionComponents, // this is a required param
+}
+
+/// The resource representing a Stripe customer session, used to initialize embedded components client-side.
+///
+/// For more details see https://stripe.com/docs/api/customer_sessions/object.
+#[derive(Debug, Deserialize)]
+pub struct CustomerSession {
+    pub object: String,
+    pub client_secret: String,
+    #[serde(default)]
+    pub components: Option<CustomerSessionComponents>,
+    pub created: Timestamp,
+    pub customer: String,
+    pub expires_at: Timestamp,
+    pub livemode: bool,
+}
+
+impl CustomerSession {
+    /// Creates a new customer session.
+    ///
+    /// For more details see https://stripe.com/docs/api/customer_sessions/create.
+    pub fn create(client: &Client, params: CustomerSessionCreateParams) -> Result<CustomerSession, Error> {
+        client.post("/customer_sessions", params)
+    }
+}
@@ -1,5 +1,13 @@
 #[derive(Deserialize)]
 pub struct Deleted {
+    // NOTE: A delete which responds `204 No Content` has no body, so the
+    //   fields default to a successful deletion of an unknown id.
+    #[serde(default = "deleted")]
     pub deleted: bool,
+    #[serde(default)]
     pub id: String,
 }
+
+fn deleted() -> bool {
+    true
+}
@@ -0,0 +1,53 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+
+/// The set of parameters that can be used when creating an ephemeral key.
+///
+/// Exactly one of `customer` or `issuing_card` should be set.
+///
+/// For more details see https://stripe.com/docs/mobile/payments.
+#[derive(Default, Serialize)]
+pub struct EphemeralKeyCreateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuing_card: Option<&'a str>,
+}
+
+/// An object which an ephemeral key grants access to.
+#[derive(Debug, Deserialize)]
+pub struct EphemeralKeyAssociatedObject {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub object_type: String, // (customer, issuing.card)
+}
+
+/// The resource representing a Stripe ephemeral key, which lets a mobile SDK
+/// access a customer's (or issuing card's) data for a short time.
+#[derive(Debug, Deserialize)]
+pub struct EphemeralKey {
+    pub id: String,
+    pub object: String,
+    pub associated_objects: Vec<EphemeralKeyAssociatedObject>,
+    pub created: Timestamp,
+    pub expires: Timestamp,
+    pub livemode: bool,
+    #[serde(default)]
+    pub secret: Option<String>, // only present when the key is created
+}
+
+impl EphemeralKey {
+    /// Creates a new ephemeral key.
+    ///
+    /// The `api_version` must be the API version used by the mobile SDK, which may
+    /// differ from the version this client otherwise uses.
+    pub fn create(client: &Client, params: EphemeralKeyCreateParams, api_version: &str) -> Result<EphemeralKey, Error> {
+        client.with_stripe_version(api_version).post("/ephemeral_keys", params)
+    }
+
+    /// Invalidates an ephemeral key before it expires.
+    pub fn delete(client: &Client, ephemeral_key_id: &str) -> Result<EphemeralKey, Error> {
+        client.delete(&Client::path_for(&["ephemeral_keys", ephemeral_key_id]))
+    }
+}
@@ -1,12 +1,16 @@
 use chrono::{Utc};
+use client::DEFAULT_API_VERSION;
 use error::{WebhookError};
+use params::deserialize_lenient;
 use resources::*;
-use hmac::{Hmac, Mac, MacResult};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json as json;
 use sha2::Sha256;
 use std::str;
 
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub enum EventType {
     #[serde(rename = "account.updated")]
     AccountUpdated,
@@ -158,14 +162,54 @@ pub enum EventType {
     TransferReversed,
     #[serde(rename = "transfer.updated")]
     TransferUpdated,
+
+    /// An event type not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, EventType::deserialize, EventType::Other)
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            EventType::Other(ref value) => serializer.serialize_str(value),
+            _ => EventType::serialize(self, serializer),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Event {
     #[serde(rename = "type")]
     pub event_type: EventType,
+    pub api_version: Option<String>, // the API version `data.object` was rendered with
     pub data: EventData,
+    pub livemode: bool,
     // ...
+
+    /// Whether `api_version` differs from the `DEFAULT_API_VERSION` this crate targets,
+    /// in which case fields of `data.object` may be missing or misread.
+    ///
+    /// This is only set by `Webhook::construct_event`.
+    #[serde(skip)]
+    pub api_version_mismatch: bool,
+}
+
+impl Event {
+    /// Returns whether this event was rendered with an API version other than `expected`.
+    ///
+    /// Events without an `api_version` are assumed to match.
+    pub fn api_version_differs(&self, expected: &str) -> bool {
+        match self.api_version {
+            Some(ref version) => version != expected,
+            None => false,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -199,6 +243,9 @@ pub enum EventObject {
     Subscription(Subscription),
     Transaction(Transaction),
     Transfer(Transfer),
+    // NOTE: Objects not yet known to this crate are dropped rather than failing the event
+    #[serde(other)]
+    Other,
 }
 
 pub struct Webhook {}
@@ -207,27 +254,21 @@ impl Webhook {
     pub fn construct_event(payload: String, sig: String, secret: String) -> Result<Event, WebhookError> {
         let mut headers: Vec<String> = sig.split(",").map(|s| s.trim().to_string()).collect();
 
-        // Prepare the signed payload
+        // Get timestamp from header
         let ref mut timestamp: Vec<String> = headers[0].split("=").map(|s| s.to_string()).collect();
-        let signed_payload = format!("{}{}{}", timestamp[1], ".", payload);
 
         // Get Stripe signature from header
         let ref mut signature: Vec<String> = headers[1].split("=").map(|s| s.to_string()).collect();
 
-        // Compute HMAC with the SHA256 hash function, using endpoing secret as key and signed_payload string as the message
-        let mut mac = Hmac::<Sha256>::new(secret.as_bytes());
-        mac.input(signed_payload.as_bytes());
-
-        let result = mac.result();
-
-        let bytes_signature = MacResult::from_slice(signature[1].as_bytes());
+        // Compute HMAC with the SHA256 hash function, using endpoint secret as key and the timestamped payload as the message
+        let expected = compute_signature(&timestamp[1], &payload, &secret);
 
         // Get current timestamp to compare to signature timestamp
         let current = Utc::now().timestamp();
         let num_timestamp = timestamp[1].parse::<i64>()
             .map_err(|err| WebhookError::BadHeader(err))?;
 
-        if bytes_signature != result {
+        if !constant_time_eq(expected.as_bytes(), signature[1].as_bytes()) {
             return Err(WebhookError::BadSignature);
         }
 
@@ -236,6 +277,27 @@ impl Webhook {
         }
 
         // return Event
-        return json::from_str(&payload).map_err(|err| WebhookError::BadParse(err));
+        let mut event: Event = json::from_str(&payload).map_err(|err| WebhookError::BadParse(err))?;
+        event.api_version_mismatch = event.api_version_differs(DEFAULT_API_VERSION);
+        Ok(event)
+    }
+
+    /// Returns a `Stripe-Signature` header for `payload`, signed as Stripe would at `timestamp`.
+    ///
+    /// This is mostly useful for testing webhook handlers.
+    pub fn signature_header(payload: &str, timestamp: i64, secret: &str) -> String {
+        format!("t={},v1={}", timestamp, compute_signature(&timestamp.to_string(), payload, secret))
     }
 }
+
+/// Computes the hex-encoded `v1` signature of a payload sent at `timestamp`.
+fn compute_signature(timestamp: &str, payload: &str, secret: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new(secret.as_bytes());
+    mac.input(format!("{}.{}", timestamp, payload).as_bytes());
+    mac.result().code().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Compares two byte strings in time independent of where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,182 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata, Object};
+use resources::{BankAccount, Card, Currency, Deleted};
+use serde::de::{self, Deserialize, Deserializer};
+use serde_json as json;
+use serde_qs as qs;
+
+/// The details of a bank account to attach to a connected account.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create#account_create_bank_account-external_account.
+#[derive(Serialize)]
+pub struct ExternalBankAccountParams<'a> {
+    object: &'static str, // always "bank_account"
+    pub account_number: &'a str,
+    pub country: &'a str, // eg. "US"
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual, company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<&'a str>,
+}
+
+impl<'a> ExternalBankAccountParams<'a> {
+    /// Creates the details of a bank account from its required fields.
+    pub fn new(account_number: &'a str, country: &'a str, currency: Currency) -> ExternalBankAccountParams<'a> {
+        ExternalBankAccountParams {
+            object: "bank_account",
+            account_number: account_number,
+            country: country,
+            currency: currency,
+            account_holder_name: None,
+            account_holder_type: None,
+            routing_number: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum ExternalAccountSource<'a> {
+    Token(&'a str), // a bank account or debit card token
+    BankAccount(ExternalBankAccountParams<'a>),
+}
+
+/// The set of parameters that can be used when creating an external account.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create.
+#[derive(Serialize)]
+pub struct ExternalAccountParams<'a> {
+    pub external_account: ExternalAccountSource<'a>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating an external account.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/update.
+#[derive(Default, Serialize)]
+pub struct ExternalAccountUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual, company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing external accounts.
+///
+/// For more details see https://stripe.com/docs/api/external_account_bank_accounts/list.
+#[derive(Default, Serialize)]
+pub struct ExternalAccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<&'a str>, // (bank_account, card)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for ExternalAccountListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// A bank account or debit card which a connected account can be paid out to.
+///
+/// For more details see https://stripe.com/docs/api/external_accounts.
+#[derive(Debug)]
+pub enum ExternalAccount {
+    BankAccount(BankAccount),
+    Card(Card),
+}
+
+impl<'de> Deserialize<'de> for ExternalAccount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = json::Value::deserialize(deserializer)?;
+        let object = value.get("object").and_then(|object| object.as_str()).unwrap_or("").to_string();
+        match object.as_str() {
+            "bank_account" => json::from_value(value).map(ExternalAccount::BankAccount).map_err(de::Error::custom),
+            "card" => json::from_value(value).map(ExternalAccount::Card).map_err(de::Error::custom),
+            _ => Err(de::Error::custom(format!("unknown external account object: {:?}", object))),
+        }
+    }
+}
+
+impl ExternalAccount {
+    /// Creates an external account for a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/create.
+    pub fn create(client: &Client, account_id: &str, params: ExternalAccountParams) -> Result<ExternalAccount, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "external_accounts"]), params)
+    }
+
+    /// Retrieves an external account of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, external_account_id: &str) -> Result<ExternalAccount, Error> {
+        client.get_at(&["accounts", account_id, "external_accounts", external_account_id])
+    }
+
+    /// Updates an external account's properties, eg. to make it the default for its currency.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/update.
+    pub fn update(
+        client: &Client,
+        account_id: &str,
+        external_account_id: &str,
+        params: ExternalAccountUpdateParams,
+    ) -> Result<ExternalAccount, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "external_accounts", external_account_id]), params)
+    }
+
+    /// Deletes an external account of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/delete.
+    pub fn delete(client: &Client, account_id: &str, external_account_id: &str) -> Result<Deleted, Error> {
+        client.delete(&Client::path_for(&["accounts", account_id, "external_accounts", external_account_id]))
+    }
+
+    /// Lists the external accounts of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api/external_account_bank_accounts/list.
+    pub fn list(client: &Client, account_id: &str, params: ExternalAccountListParams) -> Result<List<ExternalAccount>, Error> {
+        client.get(&format!("{}?{}", Client::path_for(&["accounts", account_id, "external_accounts"]), qs::to_string(&params)?))
+    }
+
+    /// Lists all external accounts of a connected account, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(
+        client: &Client,
+        account_id: &str,
+        params: ExternalAccountListParams,
+        max_items: usize,
+    ) -> Result<Vec<ExternalAccount>, Error> {
+        client.list_all(&Client::path_for(&["accounts", account_id, "external_accounts"]), params, max_items)
+    }
+}
+
+impl Object for ExternalAccount {
+    fn id(&self) -> &str {
+        match *self {
+            ExternalAccount::BankAccount(ref bank_account) => &bank_account.id,
+            ExternalAccount::Card(ref card) => &card.id,
+        }
+    }
+}
@@ -1,6 +1,55 @@
+use error::Error;
+use client::Client;
 use params::{Metadata, Timestamp};
 use resources::{Currency, Period, Plan};
 
+/// The inline specification of a price, used in place of an existing price id.
+///
+/// For more details see https://stripe.com/docs/api/invoiceitems/create#create_invoiceitem-price_data.
+#[derive(Serialize)]
+pub struct InvoiceItemPriceData<'a> {
+    pub currency: Currency,
+    pub product: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<&'a str>, // eg. "0.125" for fractional cents
+}
+
+/// The set of parameters that can be used when creating an invoice item.
+///
+/// For more details see https://stripe.com/docs/api/invoiceitems/create.
+#[derive(Default, Serialize)]
+pub struct InvoiceItemCreateParams<'a> {
+    pub customer: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<Period>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<InvoiceItemPriceData<'a>>, // NOTE: alternative to `price`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<&'a str>,
+}
+
 /// The resource representing a Stripe invoice item.
 ///
 /// For more details see https://stripe.com/docs/api#invoiceitem_object.
@@ -24,3 +73,19 @@ pub struct InvoiceItem {
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
 }
+
+impl InvoiceItem {
+    /// Creates an invoice item, which is added to the customer's next (or given) invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoiceitems/create.
+    pub fn create(client: &Client, params: InvoiceItemCreateParams) -> Result<InvoiceItem, Error> {
+        client.post("/invoiceitems", params)
+    }
+
+    /// Retrieves the details of an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoiceitems/retrieve.
+    pub fn retrieve(client: &Client, invoice_item_id: &str) -> Result<InvoiceItem, Error> {
+        client.get_at(&["invoiceitems", invoice_item_id])
+    }
+}
@@ -1,9 +1,102 @@
 use error::Error;
 use client::Client;
-use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Currency, Discount, Plan};
+use params::{deserialize_lenient, Expandable, Linked, List, ListParams, Metadata, Object, RangeQuery, Timestamp};
+use resources::{ApiError, Charge, Currency, Discount, PaymentIntent, Plan, Price, ProrationBehavior, TotalDetails};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json as json;
 use serde_qs as qs;
 
+/// How payment of an invoice is collected.
+///
+/// For more details see https://stripe.com/docs/api/invoices/object#invoice_object-collection_method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum CollectionMethod {
+    ChargeAutomatically,
+    SendInvoice,
+
+    /// A collection method not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for CollectionMethod {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, CollectionMethod::deserialize, CollectionMethod::Other)
+    }
+}
+
+impl Serialize for CollectionMethod {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            CollectionMethod::Other(ref value) => serializer.serialize_str(value),
+            _ => CollectionMethod::serialize(self, serializer),
+        }
+    }
+}
+
+/// The status of an invoice.
+///
+/// For more details see https://stripe.com/docs/invoicing/overview#invoice-statuses.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Draft,
+    Open,
+    Paid,
+    Uncollectible,
+    Void,
+
+    /// A status not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for InvoiceStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, InvoiceStatus::deserialize, InvoiceStatus::Other)
+    }
+}
+
+impl Serialize for InvoiceStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            InvoiceStatus::Other(ref value) => serializer.serialize_str(value),
+            _ => InvoiceStatus::serialize(self, serializer),
+        }
+    }
+}
+
+/// The status of automatic tax calculation for an invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/object#invoice_object-automatic_tax-status.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutomaticTaxStatus {
+    Complete,
+    Failed,
+    RequiresLocationInputs,
+    #[serde(other)]
+    Other,
+}
+
+/// The automatic tax settings of an invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/object#invoice_object-automatic_tax.
+#[derive(Debug, Default, Deserialize)]
+pub struct AutomaticTax {
+    pub enabled: bool,
+    pub status: Option<AutomaticTaxStatus>,
+}
+
+/// The set of parameters that can be used when setting an invoice's automatic tax settings.
+///
+/// For more details see https://stripe.com/docs/api/invoices/create#create_invoice-automatic_tax.
+#[derive(Default, Serialize)]
+pub struct AutomaticTaxParams {
+    pub enabled: bool,
+}
+
 /// The set of parameters that can be used when creating or updating an invoice.
 ///
 /// For more details see https://stripe.com/docs/api#create_invoice, https://stripe.com/docs/api#update_invoice.
@@ -12,8 +105,16 @@ pub struct InvoiceParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<AutomaticTaxParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub customer: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>, // NOTE: required when `collection_method` is `send_invoice`
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
@@ -48,30 +149,43 @@ pub struct InvoiceLineItemParams<'a> {
     pub subscription: Option<bool>,
 }
 
-/*
-#[derive(Serialize)]
-pub struct InvoiceListLinesParams {
-    #[serde(skip_serializing_if = "Option::is_none")] pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub ending_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub starting_after: Option<String>,
+/// The set of parameters that can be used when listing an invoice's line items.
+///
+/// For more details see https://stripe.com/docs/api#invoice_lines.
+#[derive(Default, Serialize)]
+pub struct InvoiceListLinesParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
-    ..
+impl<'a> ListParams for InvoiceListLinesParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
 }
-*/
 
 #[derive(Debug, Default, Serialize)]
 pub struct InvoiceUpcomingParams<'a> {
     pub customer: &'a str,   // this is a required param
     #[serde(skip_serializing_if = "Option::is_none")] pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription: Option<&'a str>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_items: Option<SubscriptionItemParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_items: Option<Vec<SubscriptionItemParams<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub subscription_proration_behavior: Option<ProrationBehavior>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_tax_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_trial_end: Option<Timestamp>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct SubscriptionItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<&'a str>,
@@ -82,16 +196,36 @@ pub struct SubscriptionItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
 }
 
 /// Period is a structure representing a start and end dates.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Period {
     pub start: Timestamp,
     pub end: Timestamp,
 }
 
+/// The kind of an invoice line item.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceLineItemType {
+    #[serde(rename = "invoiceitem")]
+    InvoiceItem,
+    Subscription,
+    #[serde(other)]
+    Other,
+}
+
+/// The amount of an invoice line item which was discounted by a given discount.
+#[derive(Debug, Deserialize)]
+pub struct DiscountAmount {
+    pub amount: i64,
+    pub discount: String,
+}
+
 /// The resource representing a Stripe invoice line item.
 ///
 /// For more details see https://stripe.com/docs/api#invoice_line_item_object.
@@ -101,19 +235,53 @@ pub struct InvoiceLineItem {
     pub amount: i64,
     pub currency: Currency,
     pub description: Option<String>,
+    #[serde(default)]
+    pub discount_amounts: Vec<DiscountAmount>,
     pub discountable: bool,
     pub livemode: bool,
     pub metadata: Metadata,
     pub period: Period,
     pub plan: Option<Plan>,
+    #[serde(default)]
+    pub price: Option<Price>,
     pub proration: bool,
     pub quantity: Option<u64>,
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
     #[serde(default)]
+    pub tax_rates: Vec<json::Value>,
+    #[serde(default)]
     // NOTE: Missing in response to InvoiceLineItem create
     #[serde(rename = "type")]
-    pub item_type: String, // (invoiceitem, subscription)
+    pub item_type: Option<InvoiceLineItemType>,
+}
+
+/// The payment settings of an invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/object#invoice_object-payment_settings.
+#[derive(Debug, Deserialize)]
+pub struct InvoicePaymentSettings {
+    #[serde(default)]
+    pub default_mandate: Option<String>,
+    #[serde(default)]
+    pub payment_method_types: Option<Vec<String>>, // NOTE: if None, the invoice template's settings are used
+}
+
+/// The set of parameters that can be used when paying an invoice.
+///
+/// For more details see https://stripe.com/docs/api/invoices/pay.
+#[derive(Default, Serialize)]
+pub struct InvoicePayParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forgive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_out_of_band: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
 }
 
 /// The resource representing a Stripe invoice.
@@ -126,31 +294,51 @@ pub struct Invoice {
     pub application_fee: Option<u64>,
     pub attempt_count: u64,
     pub attempted: bool,
-    pub charge: Option<String>,
+    #[serde(default)]
+    pub auto_advance: Option<bool>,
+    #[serde(default)]
+    pub automatic_tax: Option<AutomaticTax>,
+    pub charge: Option<Expandable<Charge>>,
     pub closed: bool,
+    #[serde(default)]
+    pub collection_method: Option<CollectionMethod>,
     pub currency: Currency,
     pub customer: String,
     pub date: Timestamp,
+    #[serde(default)]
+    pub days_until_due: Option<u32>,
     pub description: Option<String>,
     pub discount: Option<Discount>,
     pub ending_balance: Option<i64>,
     pub forgiven: bool,
+    #[serde(default)]
+    pub last_finalization_error: Option<ApiError>,
     pub lines: List<InvoiceLineItem>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub next_payment_attempt: Option<Timestamp>,
     pub paid: bool,
+    #[serde(default)]
+    pub paid_out_of_band: bool,
+    #[serde(default)]
+    pub payment_intent: Option<Expandable<PaymentIntent>>,
+    #[serde(default)]
+    pub payment_settings: Option<InvoicePaymentSettings>,
     pub period_end: Timestamp,
     pub period_start: Timestamp,
     pub receipt_number: Option<String>,
     pub starting_balance: i64,
     pub statment_descriptor: Option<String>,
+    #[serde(default)]
+    pub status: Option<InvoiceStatus>, // NOTE: missing in API versions before 2018-02-28
     pub subscription: Option<String>,
     pub subscription_proration_date: Option<Timestamp>,
     pub subtotal: i64,
     pub tax: Option<i64>,
     pub tax_percent: Option<f64>,
     pub total: i64,
+    #[serde(default)]
+    pub total_details: Option<TotalDetails>,
     pub webhooks_delivered_at: Option<Timestamp>,
 }
 
@@ -167,9 +355,21 @@ pub struct InvoiceListParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starting_after: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InvoiceStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
 }
 
+impl<'a> ListParams for InvoiceListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
 impl Invoice {
     /// Creates a new invoice.
     ///
@@ -182,13 +382,20 @@ impl Invoice {
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_invoice.
     pub fn retrieve(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
-        client.get(&format!("/invoices/{}", invoice_id))
+        client.get_at(&["invoices", invoice_id])
     }
 
-    // TODO: Implement InvoiceListLinesParams
-    // pub fn get_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
-    //     client.get(&format!("/invoices/{}/lines", invoice_id))
-    // }
+    /// Lists a page of an invoice's line items.
+    ///
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn list_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
+        client.get(&format!("{}?{}", Client::path_for(&["invoices", invoice_id, "lines"]), qs::to_string(&params)?))
+    }
+
+    /// Lists all of an invoice's line items, following pagination, failing if there are more than `max_items`.
+    pub fn list_all_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams, max_items: usize) -> Result<Vec<InvoiceLineItem>, Error> {
+        client.list_all(&Client::path_for(&["invoices", invoice_id, "lines"]), params, max_items)
+    }
 
     /// Retrieves the details of an upcoming invoice_id
     ///
@@ -201,14 +408,57 @@ impl Invoice {
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
     pub fn pay(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
-        client.post_empty(&format!("/invoices/{}/pay", invoice_id))
+        client.post_empty(&Client::path_for(&["invoices", invoice_id, "pay"]))
+    }
+
+    /// Pays an invoice with the given parameters (eg. to `forgive` a partial payment).
+    ///
+    /// For more details see https://stripe.com/docs/api#pay_invoice.
+    pub fn pay_with(client: &Client, invoice_id: &str, params: InvoicePayParams) -> Result<Invoice, Error> {
+        client.post(&Client::path_for(&["invoices", invoice_id, "pay"]), params)
+    }
+
+    /// Finalizes a draft invoice, without emailing it to the customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/finalize.
+    pub fn finalize_invoice(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&Client::path_for(&["invoices", invoice_id, "finalize"]))
+    }
+
+    /// Emails an invoice to the customer, finalizing it first if it is a draft.
+    ///
+    /// Calling this on an invoice which was already sent sends the email again.  Only invoices
+    /// with a `send_invoice` collection method can be sent; for other invoices Stripe's error
+    /// message explains why the invoice can't be sent.
+    ///
+    /// For more details see https://stripe.com/docs/api/invoices/send.
+    pub fn send_invoice(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post_empty(&Client::path_for(&["invoices", invoice_id, "send"]))
+    }
+
+    /// Emails this invoice to the customer, like `Invoice::send_invoice`.
+    ///
+    /// Only open (or draft) invoices with a `send_invoice` collection method can be sent,
+    /// so for other invoices this fails with `Error::InvalidOperation` without making a request.
+    pub fn send(&self, client: &Client) -> Result<Invoice, Error> {
+        match self.status {
+            Some(InvoiceStatus::Open) | Some(InvoiceStatus::Draft) | None => {}
+            Some(_) => return Err(Error::InvalidOperation("can only send open or draft invoices")),
+        }
+        if self.collection_method == Some(CollectionMethod::ChargeAutomatically) {
+            return Err(Error::InvalidOperation("can only send invoices whose collection method is send_invoice"));
+        }
+        match self.id {
+            Some(ref id) => Invoice::send_invoice(client, id),
+            None => Err(Error::InvalidOperation("can't send an upcoming invoice")),
+        }
     }
 
     /// Updates an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#update_invoice.
     pub fn update(client: &Client, invoice_id: &str, params: InvoiceParams) -> Result<Invoice, Error> {
-        client.post(&format!("/invoices/{}", invoice_id), &params)
+        client.post(&Client::path_for(&["invoices", invoice_id]), &params)
     }
 
     /// Lists all invoices.
@@ -217,6 +467,27 @@ impl Invoice {
     pub fn list(client: &Client, params: InvoiceListParams) -> Result<List<Invoice>, Error> {
         client.get(&format!("/invoices?{}", qs::to_string(&params)?))
     }
+
+    /// Lists all invoices, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: InvoiceListParams, max_items: usize) -> Result<Vec<Invoice>, Error> {
+        client.list_all("/invoices", params, max_items)
+    }
+
+    /// Returns the latest charge for this invoice, fetching it if it wasn't expanded.
+    pub fn charge(&self, client: &Client) -> Result<Option<Linked<Charge>>, Error> {
+        match self.charge {
+            Some(ref charge) => charge.get_or_fetch(|id| Charge::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the payment intent for this invoice, fetching it if it wasn't expanded.
+    pub fn payment_intent(&self, client: &Client) -> Result<Option<Linked<PaymentIntent>>, Error> {
+        match self.payment_intent {
+            Some(ref intent) => intent.get_or_fetch(|id| PaymentIntent::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 impl InvoiceLineItem {
@@ -227,3 +498,125 @@ impl InvoiceLineItem {
         client.post(&format!("/invoiceitems"), &params)
     }
 }
+
+impl Object for Invoice {
+    /// Returns the invoice's id, or an empty string for an upcoming invoice
+    /// (which pagination refuses to use as a cursor).
+    fn id(&self) -> &str {
+        self.id.as_ref().map(|id| id.as_str()).unwrap_or("")
+    }
+}
+
+impl Object for InvoiceLineItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn payment_intent_link() {
+        use super::Invoice;
+        use client::Client;
+        use mock::{ok, serve};
+        use serde_json as json;
+
+        let intent = r#"{
+            "id": "pi_123", "object": "payment_intent", "amount": 2000, "amount_capturable": 0,
+            "amount_received": 2000, "capture_method": "automatic", "confirmation_method": "automatic",
+            "created": 1500000000, "currency": "usd", "livemode": false, "metadata": {},
+            "payment_method_types": ["card"], "status": "succeeded"
+        }"#;
+        let invoice = |payment_intent: &str| format!(r#"{{
+            "id": "in_123", "amount_due": 2000, "attempt_count": 1, "attempted": true, "closed": true,
+            "currency": "usd", "customer": "cus_123", "date": 1500000000, "forgiven": false,
+            "lines": {{"data": [], "has_more": false, "url": "/v1/invoices/in_123/lines"}},
+            "livemode": false, "metadata": {{}}, "paid": true, "payment_intent": {},
+            "period_end": 1500000000, "period_start": 1500000000, "starting_balance": 0,
+            "subtotal": 2000, "total": 2000
+        }}"#, payment_intent);
+
+        // An unexpanded link is fetched from the API
+        let (api_base, _server) = serve(ok(vec![intent.to_string()]));
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let unexpanded: Invoice = json::from_str(&invoice(r#""pi_123""#)).unwrap();
+        let fetched = unexpanded.payment_intent(&client).unwrap().unwrap();
+        assert_eq!(fetched.amount_received, 2000);
+        assert_eq!(client.request_count(), 1);
+
+        // An expanded link is returned without a request
+        let expanded: Invoice = json::from_str(&invoice(intent)).unwrap();
+        let linked = expanded.payment_intent(&client).unwrap().unwrap();
+        assert_eq!(linked.id, "pi_123");
+        assert_eq!(client.request_count(), 1);
+    }
+
+    #[test]
+    fn send_invoice_error() {
+        use super::Invoice;
+        use client::Client;
+        use error::Error;
+        use mock::serve;
+
+        let error = r#"{"error":{"type":"invalid_request_error","message":"You can only manually send an invoice if its collection method is 'send_invoice'."}}"#;
+        let (api_base, _server) = serve(vec![("400 Bad Request", error.to_string())]);
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        match Invoice::send_invoice(&client, "in_123") {
+            Err(err @ Error::Stripe(_)) => assert!(err.to_string().contains("collection method is 'send_invoice'")),
+            other => panic!("expected an invalid request error, got {:?}", other.map(|invoice| invoice.id)),
+        }
+    }
+
+    #[test]
+    fn send_paid() {
+        use super::Invoice;
+        use client::Client;
+        use error::Error;
+        use mock::UNREACHABLE;
+        use serde_json as json;
+
+        let invoice = |status: &str, collection_method: &str| -> Invoice {
+            json::from_str(&format!(r#"{{
+                "id": "in_123", "amount_due": 2000, "attempt_count": 1, "attempted": true, "closed": true,
+                "collection_method": "{}", "currency": "usd", "customer": "cus_123", "date": 1500000000,
+                "forgiven": false, "lines": {{"data": [], "has_more": false, "url": "/v1/invoices/in_123/lines"}},
+                "livemode": false, "metadata": {{}}, "paid": true, "period_end": 1500000000,
+                "period_start": 1500000000, "starting_balance": 0, "status": "{}", "subtotal": 2000, "total": 2000
+            }}"#, collection_method, status)).unwrap()
+        };
+
+        let client = Client::new("sk_test_123").with_api_base(UNREACHABLE);
+        match invoice("paid", "send_invoice").send(&client) {
+            Err(Error::InvalidOperation(msg)) => assert_eq!(msg, "can only send open or draft invoices"),
+            other => panic!("expected an invalid operation error, got {:?}", other.map(|invoice| invoice.id)),
+        }
+        match invoice("open", "charge_automatically").send(&client) {
+            Err(Error::InvalidOperation(msg)) => assert!(msg.contains("collection method")),
+            other => panic!("expected an invalid operation error, got {:?}", other.map(|invoice| invoice.id)),
+        }
+        match invoice("open", "send_invoice").send(&client) {
+            Err(Error::Http(_)) => {}
+            other => panic!("expected an http error, got {:?}", other.map(|invoice| invoice.id)),
+        }
+    }
+
+    #[test]
+    fn send_invoice_requires_days_until_due() {
+        use super::{CollectionMethod, Invoice, InvoiceParams};
+        use client::Client;
+        use error::Error;
+        use mock::serve;
+
+        let error = r#"{"error":{"type":"invalid_request_error","message":"Missing required param: days_until_due.","param":"days_until_due"}}"#;
+        let (api_base, _server) = serve(vec![("400 Bad Request", error.to_string())]);
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let mut params = InvoiceParams::default();
+        params.customer = Some("cus_123");
+        params.collection_method = Some(CollectionMethod::SendInvoice);
+        match Invoice::create(&client, params) {
+            Err(Error::Stripe(err)) => assert_eq!(err.param, Some("days_until_due".to_string())),
+            other => panic!("expected an invalid request error, got {:?}", other.map(|invoice| invoice.id)),
+        }
+    }
+}
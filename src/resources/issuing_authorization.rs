@@ -0,0 +1,82 @@
+use error::Error;
+use client::Client;
+use params::{Metadata, Timestamp};
+use resources::Currency;
+
+/// The set of parameters that can be used when approving an issuing authorization.
+///
+/// If `amount` is not set, the full requested amount is approved.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/approve.
+#[derive(Default, Serialize)]
+pub struct IssuingAuthorizationApproveParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when declining an issuing authorization.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/decline.
+#[derive(Default, Serialize)]
+pub struct IssuingAuthorizationDeclineParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The resource representing a Stripe issuing authorization.
+///
+/// For more details see https://stripe.com/docs/api/issuing/authorizations/object.
+#[derive(Debug, Deserialize)]
+pub struct IssuingAuthorization {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub approved: bool,
+    pub authorization_method: String, // (chip, contactless, keyed_in, online, swipe)
+    pub card: String,
+    pub cardholder: Option<String>,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub merchant_amount: i64,
+    pub merchant_currency: Currency,
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub pending_request: Option<IssuingAuthorizationPendingRequest>,
+    pub status: String, // (pending, closed, reversed)
+}
+
+/// The details of an authorization awaiting a real-time approval decision.
+#[derive(Debug, Deserialize)]
+pub struct IssuingAuthorizationPendingRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub is_amount_controllable: bool,
+    pub merchant_amount: i64,
+    pub merchant_currency: Currency,
+}
+
+impl IssuingAuthorization {
+    /// Retrieves the details of an issuing authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/retrieve.
+    pub fn retrieve(client: &Client, authorization_id: &str) -> Result<IssuingAuthorization, Error> {
+        client.get_at(&["issuing", "authorizations", authorization_id])
+    }
+
+    /// Approves a pending issuing authorization, optionally for a different amount than requested.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/approve.
+    pub fn approve(client: &Client, authorization_id: &str, params: IssuingAuthorizationApproveParams) -> Result<IssuingAuthorization, Error> {
+        client.post(&Client::path_for(&["issuing", "authorizations", authorization_id, "approve"]), params)
+    }
+
+    /// Declines a pending issuing authorization.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/authorizations/decline.
+    pub fn decline(client: &Client, authorization_id: &str, params: IssuingAuthorizationDeclineParams) -> Result<IssuingAuthorization, Error> {
+        client.post(&Client::path_for(&["issuing", "authorizations", authorization_id, "decline"]), params)
+    }
+}
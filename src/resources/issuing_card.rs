@@ -0,0 +1,68 @@
+use error::Error;
+use client::Client;
+use params::{Metadata, Timestamp};
+use resources::Currency;
+use serde_qs as qs;
+
+/// The set of parameters that can be used when retrieving an issuing card's details.
+///
+/// For more details see https://stripe.com/docs/issuing/cards/virtual.
+#[derive(Default, Serialize)]
+pub struct CardDetailsParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<Vec<&'a str>>,
+}
+
+/// The sensitive details of an issuing card.
+///
+/// This type intentionally does not implement `Debug` so the card number and cvc
+/// cannot end up in logs by accident.
+#[derive(Deserialize)]
+pub struct CardDetails {
+    pub cvc: String,
+    pub exp_month: u32,
+    pub exp_year: u32,
+    pub number: String,
+}
+
+/// The resource representing a Stripe issuing card.
+///
+/// For more details see https://stripe.com/docs/api/issuing/cards/object.
+#[derive(Debug, Deserialize)]
+pub struct IssuingCard {
+    pub id: String,
+    pub object: String,
+    pub brand: String,
+    pub cancellation_reason: Option<String>, // (lost, stolen)
+    pub cardholder: Option<String>,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub exp_month: u32,
+    pub exp_year: u32,
+    pub last4: String,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub status: String, // (active, inactive, canceled)
+    #[serde(rename = "type")]
+    pub card_type: String, // (physical, virtual)
+}
+
+impl IssuingCard {
+    /// Retrieves the details of an issuing card.
+    ///
+    /// For more details see https://stripe.com/docs/api/issuing/cards/retrieve.
+    pub fn retrieve(client: &Client, card_id: &str) -> Result<IssuingCard, Error> {
+        client.get_at(&["issuing", "cards", card_id])
+    }
+
+    /// Retrieves the full card number, cvc and expiry of an issuing card.
+    ///
+    /// **Warning:** the response contains sensitive cardholder data which is in PCI scope.
+    /// Only call this server-side or from a PCI-compliant environment, and never log or
+    /// persist the result.
+    ///
+    /// For more details see https://stripe.com/docs/issuing/cards/virtual.
+    pub fn retrieve_details(client: &Client, card_id: &str, params: CardDetailsParams) -> Result<CardDetails, Error> {
+        client.get(&format!("{}?{}", Client::path_for(&["issuing", "cards", card_id, "details"]), qs::to_string(&params)?))
+    }
+}
@@ -1,63 +1,114 @@
 mod account;
 mod address;
+mod api_error;
 mod application_fee;
 mod application_refund;
 mod balance;
 mod bank_account;
+mod billing_cycle_anchor;
+mod capability;
 mod card;
 mod charge;
+mod checkout_session;
+mod confirmation_token;
+mod connect;
 mod coupon;
 mod currency;
 mod customer;
+mod customer_session;
 mod deleted;
 mod discount;
 mod dispute;
+mod ephemeral_key;
 mod event;
+mod external_account;
 mod file;
 mod invoices;
 mod invoice_item;
+mod issuing_authorization;
+mod issuing_card;
+mod oauth;
 mod order;
 mod order_return;
+mod payment_intent;
+mod payment_method;
 mod payout;
+mod person;
 mod plan;
+mod price;
 mod product;
+mod proration_behavior;
+mod recurring;
 mod refund;
 mod review;
 mod scheduled_query;
+mod setup_intent;
 mod sku;
 mod source;
 mod subscription;
+mod tax_rate;
+pub mod terminal;
+mod test_clock;
+mod topup;
+mod total_details;
 mod transaction;
 mod transfer;
+mod transfer_data;
+mod usage_record;
 
 pub use resources::account::*;
 pub use resources::address::*;
+pub use resources::api_error::*;
 pub use resources::application_fee::*;
 pub use resources::application_refund::*;
 pub use resources::balance::*;
 pub use resources::bank_account::*;
+pub use resources::billing_cycle_anchor::*;
+pub use resources::capability::*;
 pub use resources::card::*;
 pub use resources::charge::*;
+pub use resources::checkout_session::*;
+pub use resources::confirmation_token::*;
+pub use resources::connect::*;
 pub use resources::coupon::*;
 pub use resources::currency::*;
 pub use resources::customer::*;
+pub use resources::customer_session::*;
 pub use resources::deleted::*;
 pub use resources::discount::*;
 pub use resources::dispute::*;
+pub use resources::ephemeral_key::*;
 pub use resources::event::*;
+pub use resources::external_account::*;
 pub use resources::file::*;
 pub use resources::invoices::*;
 pub use resources::invoice_item::*;
+pub use resources::issuing_authorization::*;
+pub use resources::issuing_card::*;
+pub use resources::oauth::*;
 pub use resources::order::*;
 pub use resources::order_return::*;
+pub use resources::payment_intent::*;
+pub use resources::payment_method::*;
 pub use resources::payout::*;
+pub use resources::person::*;
 pub use resources::plan::*;
+pub use resources::price::*;
 pub use resources::product::*;
+pub use resources::proration_behavior::*;
+pub use resources::recurring::*;
 pub use resources::refund::*;
 pub use resources::review::*;
 pub use resources::scheduled_query::*;
+pub use resources::setup_intent::*;
 pub use resources::sku::*;
 pub use resources::source::*;
 pub use resources::subscription::*;
+pub use resources::tax_rate::*;
+pub use resources::test_clock::*;
+pub use resources::topup::*;
+pub use resources::total_details::*;
 pub use resources::transaction::*;
 pub use resources::transfer::*;
+pub use resources::transfer_data::*;
+pub use resources::usage_record::*;
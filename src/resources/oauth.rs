@@ -0,0 +1,72 @@
+use error::Error;
+use client::Client;
+
+/// The type of grant being exchanged for an access token.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrantType {
+    AuthorizationCode,
+    RefreshToken,
+}
+
+#[derive(Serialize)]
+struct TokenParams<'a> {
+    grant_type: GrantType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct DeauthorizeParams<'a> {
+    client_id: &'a str,
+    stripe_user_id: &'a str,
+}
+
+/// The response to a successful Connect OAuth token request.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+#[derive(Debug, Deserialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+    pub livemode: Option<bool>,
+    pub refresh_token: Option<String>,
+    pub scope: String, // (read_only, read_write)
+    pub stripe_publishable_key: Option<String>,
+    pub stripe_user_id: String,
+    pub token_type: Option<String>,
+}
+
+/// The response to a successful Connect OAuth deauthorization.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize.
+#[derive(Debug, Deserialize)]
+pub struct OAuthDeauthorization {
+    pub stripe_user_id: String,
+}
+
+pub struct OAuth {}
+
+impl OAuth {
+    /// Exchanges an authorization code (or a refresh token) for a connected account's credentials.
+    ///
+    /// When `grant_type` is `RefreshToken`, `code` should be the refresh token.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+    pub fn token(client: &Client, code: &str, grant_type: GrantType) -> Result<OAuthToken, Error> {
+        let params = match grant_type {
+            GrantType::AuthorizationCode => TokenParams { grant_type: grant_type, code: Some(code), refresh_token: None },
+            GrantType::RefreshToken => TokenParams { grant_type: grant_type, code: None, refresh_token: Some(code) },
+        };
+        client.post_connect("/oauth/token", params)
+    }
+
+    /// Revokes a connected account's access to the platform.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize.
+    pub fn deauthorize(client: &Client, client_id: &str, stripe_user_id: &str) -> Result<OAuthDeauthorization, Error> {
+        let params = DeauthorizeParams { client_id: client_id, stripe_user_id: stripe_user_id };
+        client.post_connect("/oauth/deauthorize", params)
+    }
+}
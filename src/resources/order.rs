@@ -1,5 +1,5 @@
 use params::{List, Metadata, Timestamp};
-use resources::{Currency, ShippingDetails};
+use resources::{Currency, Shipping};
 use serde_json as json;
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -51,7 +51,7 @@ pub struct Order {
     pub metadata: Metadata,
     pub returns: List<json::Value>,
     pub selected_shipping_method: Option<String>,
-    pub shipping: Option<ShippingDetails>,
+    pub shipping: Option<Shipping>,
     pub shipping_methods: List<json::Value>,
     pub status: String, // (created, paid, canceled, fulfilled, returned)
     pub status_transitions: StatusTransitions,
@@ -0,0 +1,723 @@
+use error::Error;
+use client::Client;
+use params::{deserialize_lenient, Expandable, Linked, List, ListParams, Metadata, Object, RangeQuery, Timestamp};
+use resources::{ApiError, Charge, Currency, Interval, PaymentMethodData, Shipping, TransferData, TransferDataParams, TransferDataUpdateParams};
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer};
+use serde::{Serialize, Serializer};
+use serde_json as json;
+use serde_qs as qs;
+use std::cmp;
+use std::thread;
+use std::time::Duration;
+
+/// The error which caused the last payment attempt on a payment intent to fail.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-last_payment_error.
+pub type PaymentIntentLastPaymentError = ApiError;
+
+#[derive(Debug, Deserialize)]
+pub struct NextActionRedirectToUrl {
+    pub url: String,
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NextActionVerifyWithMicrodeposits {
+    pub arrival_date: Timestamp,
+    pub hosted_verification_url: String,
+    pub microdeposit_type: Option<String>, // (amounts, descriptor_code)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NextActionDisplayBankTransferInstructions {
+    pub amount_remaining: Option<u64>,
+    pub currency: Option<Currency>,
+    pub hosted_instructions_url: Option<String>,
+    pub reference: Option<String>,
+    #[serde(rename = "type")]
+    pub instructions_type: String, // (eu_bank_transfer, gb_bank_transfer, jp_bank_transfer, mx_bank_transfer, us_bank_transfer)
+}
+
+/// The type of a `NextAction`.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action-type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NextActionType {
+    RedirectToUrl,
+    UseStripeSdk,
+    DisplayBankTransferInstructions,
+    VerifyWithMicrodeposits,
+    Other,
+}
+
+/// The action a customer must take to continue a payment or setup intent.
+///
+/// Unrecognized action types deserialize into `Other` with the raw `next_action` object.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action.
+#[derive(Debug)]
+pub enum NextAction {
+    RedirectToUrl(NextActionRedirectToUrl),
+    UseStripeSdk(json::Value),
+    DisplayBankTransferInstructions(NextActionDisplayBankTransferInstructions),
+    VerifyWithMicrodeposits(NextActionVerifyWithMicrodeposits),
+    Other(json::Value),
+}
+
+impl<'de> Deserialize<'de> for NextAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = json::Value::deserialize(deserializer)?;
+        let action_type = match value.get("type").and_then(|t| t.as_str()) {
+            Some(action_type) => action_type.to_string(),
+            None => return Ok(NextAction::Other(value)),
+        };
+
+        // NOTE: The details of each action are nested under a key matching its type
+        let action = match action_type.as_str() {
+            "redirect_to_url" => NextAction::RedirectToUrl(action_details(&mut value, &action_type)?),
+            "use_stripe_sdk" => NextAction::UseStripeSdk(action_details(&mut value, &action_type)?),
+            "display_bank_transfer_instructions" => {
+                NextAction::DisplayBankTransferInstructions(action_details(&mut value, &action_type)?)
+            }
+            "verify_with_microdeposits" => NextAction::VerifyWithMicrodeposits(action_details(&mut value, &action_type)?),
+            _ => NextAction::Other(value),
+        };
+        Ok(action)
+    }
+}
+
+impl NextAction {
+    /// Returns the type of this action.
+    pub fn action_type(&self) -> NextActionType {
+        match *self {
+            NextAction::RedirectToUrl(_) => NextActionType::RedirectToUrl,
+            NextAction::UseStripeSdk(_) => NextActionType::UseStripeSdk,
+            NextAction::DisplayBankTransferInstructions(_) => NextActionType::DisplayBankTransferInstructions,
+            NextAction::VerifyWithMicrodeposits(_) => NextActionType::VerifyWithMicrodeposits,
+            NextAction::Other(_) => NextActionType::Other,
+        }
+    }
+}
+
+fn action_details<T: DeserializeOwned, E: de::Error>(value: &mut json::Value, action_type: &str) -> Result<T, E> {
+    let details = value.as_object_mut().and_then(|obj| obj.remove(action_type)).unwrap_or(json::Value::Null);
+    json::from_value(details).map_err(E::custom)
+}
+
+/// When to request 3D Secure authentication of a card payment.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create#create_payment_intent-payment_method_options-card-request_three_d_secure.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestThreeDSecure {
+    /// Request 3D Secure when required by regulation or Radar rules.
+    Automatic,
+    /// Request 3D Secure whenever it is supported by the card.
+    Any,
+}
+
+/// An installment plan for a card payment.
+///
+/// For more details see https://stripe.com/docs/payments/installments.
+#[derive(Serialize)]
+pub struct InstallmentPlan<'a> {
+    pub count: u64,
+    pub interval: Interval,
+    #[serde(rename = "type")]
+    pub plan_type: &'a str, // (fixed_count)
+}
+
+/// The installments options of a card payment.
+#[derive(Default, Serialize)]
+pub struct CardInstallmentsOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<InstallmentPlan<'a>>,
+}
+
+/// The card-specific options of a payment intent.
+#[derive(Default, Serialize)]
+pub struct CardPaymentMethodOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<&'a str>, // (manual)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments: Option<CardInstallmentsOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<&'a str>, // (amex, cartes_bancaires, diners, discover, interac, jcb, mastercard, unionpay, visa, ..)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_three_d_secure: Option<RequestThreeDSecure>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (none, on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix_kana: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix_kanji: Option<&'a str>,
+}
+
+/// How a bank account is verified before it can be debited.
+///
+/// For more details see https://stripe.com/docs/payments/ach-debit#verification.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationMethod {
+    /// Verify instantly if possible, falling back to micro-deposits.
+    Automatic,
+    /// Only verify instantly (eg. by logging in to the bank).
+    Instant,
+    /// Only verify with micro-deposits.
+    Microdeposits,
+}
+
+/// The mandate options of a Canadian pre-authorized debit.
+#[derive(Default, Serialize)]
+pub struct AcssDebitMandateOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_mandate_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_schedule: Option<&'a str>, // (interval, sporadic, combined)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<&'a str>, // (personal, business)
+}
+
+/// The Canadian pre-authorized debit (ACSS) options of a payment intent.
+#[derive(Default, Serialize)]
+pub struct AcssDebitPaymentMethodOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate_options: Option<AcssDebitMandateOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (none, on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<VerificationMethod>,
+}
+
+/// The SEPA Direct Debit options of a payment intent.
+#[derive(Default, Serialize)]
+pub struct SepaDebitPaymentMethodOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (none, on_session, off_session)
+}
+
+/// The US bank account (ACH Direct Debit) options of a payment intent.
+#[derive(Default, Serialize)]
+pub struct UsBankAccountPaymentMethodOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_settlement_speed: Option<&'a str>, // (standard, fastest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (none, on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<VerificationMethod>,
+}
+
+/// The payment-method-specific options of a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create#create_payment_intent-payment_method_options.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodOptions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<AcssDebitPaymentMethodOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardPaymentMethodOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SepaDebitPaymentMethodOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<UsBankAccountPaymentMethodOptions<'a>>,
+}
+
+/// Whether automatic payment methods which redirect the customer (eg. to their bank) are allowed.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create#create_payment_intent-automatic_payment_methods-allow_redirects.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum AllowRedirects {
+    Always,
+    Never,
+
+    /// A value not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for AllowRedirects {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, AllowRedirects::deserialize, AllowRedirects::Other)
+    }
+}
+
+impl Serialize for AllowRedirects {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            AllowRedirects::Other(ref value) => serializer.serialize_str(value),
+            _ => AllowRedirects::serialize(self, serializer),
+        }
+    }
+}
+
+/// Whether Stripe picks the payment methods of a payment intent, based on the
+/// customer's location and the payment methods enabled in the Dashboard.
+///
+/// For more details see https://stripe.com/docs/payments/payment-methods/integration-options#using-automatic-payment-methods.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AutomaticPaymentMethods {
+    pub enabled: bool,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_redirects: Option<AllowRedirects>,
+}
+
+/// The set of parameters that can be used when creating or updating a payment intent.
+///
+/// NOTE: `automatic_payment_methods` can't be enabled together with `payment_method_types`.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create and https://stripe.com/docs/api/payment_intents/update.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<AutomaticPaymentMethods>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<&'a str>, // (automatic, manual)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_data: Option<PaymentMethodData<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_options: Option<PaymentMethodOptions<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a payment intent.
+///
+/// A payment intent's destination can't be changed, so only the transferred `amount` can be updated.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/update.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (on_session, off_session)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataUpdateParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when confirming a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_future_usage: Option<&'a str>, // (on_session, off_session)
+}
+
+/// The set of parameters that can be used when capturing a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/capture.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCaptureParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_to_capture: Option<i64>, // NOTE: if None, the full amount_capturable is captured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_capture: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataUpdateParams>,
+}
+
+/// The set of parameters that can be used when incrementing a payment intent's authorization.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/increment_authorization.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentIncrementalAuthorizationParams {
+    pub amount: i64, // the new total amount, not the increment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<TransferDataUpdateParams>,
+}
+
+/// The set of parameters that can be used when verifying micro-deposits on a payment intent.
+///
+/// Provide either the two deposit `amounts` or the `descriptor_code`, but not both.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/verify_microdeposits.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentVerifyMicrodepositsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<Vec<i64>>, // in cents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_code: Option<String>, // eg. "SM11AA"
+}
+
+/// The set of parameters that can be used when canceling a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCancelParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<&'static str>, // (duplicate, fraudulent, requested_by_customer, abandoned)
+}
+
+/// The set of parameters that can be used when listing payment intents.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/list.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for PaymentIntentListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The status of a payment intent.
+///
+/// For more details see https://stripe.com/docs/payments/intents#intent-statuses.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentIntentStatus {
+    Canceled,
+    Processing,
+    RequiresAction,
+    RequiresCapture,
+    RequiresConfirmation,
+    RequiresPaymentMethod,
+    Succeeded,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object.
+#[derive(Debug, Deserialize)]
+pub struct PaymentIntent {
+    pub id: String,
+    pub object: String,
+    pub amount: u64,
+    pub amount_capturable: u64,
+    pub amount_received: u64,
+    pub application_fee_amount: Option<u64>,
+    #[serde(default)]
+    pub automatic_payment_methods: Option<AutomaticPaymentMethods>,
+    pub canceled_at: Option<Timestamp>,
+    pub cancellation_reason: Option<String>,
+    pub capture_method: String, // (automatic, manual)
+    #[serde(default)]
+    pub charges: Option<List<Charge>>, // only present in API versions before 2022-11-15
+    pub client_secret: Option<String>,
+    pub confirmation_method: String, // (automatic, manual)
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub last_payment_error: Option<ApiError>,
+    #[serde(default)]
+    pub latest_charge: Option<Expandable<Charge>>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub next_action: Option<NextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_types: Vec<String>,
+    pub receipt_email: Option<String>,
+    pub setup_future_usage: Option<String>,
+    pub shipping: Option<Shipping>,
+    pub statement_descriptor: Option<String>,
+    pub status: PaymentIntentStatus,
+    #[serde(default)]
+    pub transfer_data: Option<TransferData>,
+    pub transfer_group: Option<String>,
+}
+
+impl PaymentIntent {
+    /// Returns whether this payment intent's status is final (ie. `Canceled` or `Succeeded`).
+    pub fn is_terminal(&self) -> bool {
+        match self.status {
+            PaymentIntentStatus::Canceled | PaymentIntentStatus::Succeeded => true,
+            _ => false,
+        }
+    }
+
+    /// Creates a new payment intent.
+    ///
+    /// Enabling `automatic_payment_methods` while also giving `payment_method_types`
+    /// fails with `Error::Unsupported` without making a request.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/create.
+    pub fn create(client: &Client, params: PaymentIntentParams) -> Result<PaymentIntent, Error> {
+        let automatic = params.automatic_payment_methods.as_ref().map_or(false, |methods| methods.enabled);
+        if automatic && params.payment_method_types.is_some() {
+            return Err(Error::Unsupported("can't enable automatic_payment_methods with payment_method_types"));
+        }
+        client.post("/payment_intents", params)
+    }
+
+    /// Retrieves the details of a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/retrieve.
+    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Result<PaymentIntent, Error> {
+        client.get_at(&["payment_intents", payment_intent_id])
+    }
+
+    /// Polls a payment intent every `interval` until its status is one of `target_statuses`
+    /// or terminal (eg. to wait for a customer to complete `RequiresAction`).
+    ///
+    /// Any other status keeps polling, including ones which wait on the caller rather than
+    /// Stripe (eg. `RequiresPaymentMethod` after a failed authentication), so list those in
+    /// `target_statuses` to stop at them.  With no `target_statuses`, this polls until the
+    /// payment intent is `Canceled` or `Succeeded`.
+    ///
+    /// Returns the payment intent once it stops, or `Error::Timeout` if it
+    /// hasn't after `max_attempts` retrievals (at least one retrieval is always made).
+    pub fn poll_until(
+        client: &Client,
+        payment_intent_id: &str,
+        target_statuses: &[PaymentIntentStatus],
+        interval: Duration,
+        max_attempts: usize,
+    ) -> Result<PaymentIntent, Error> {
+        for attempt in 0..cmp::max(max_attempts, 1) {
+            if attempt > 0 {
+                thread::sleep(interval);
+            }
+            let intent = PaymentIntent::retrieve(client, payment_intent_id)?;
+            if intent.is_terminal() || target_statuses.contains(&intent.status) {
+                return Ok(intent);
+            }
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Updates a payment intent's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/update.
+    pub fn update(client: &Client, payment_intent_id: &str, params: PaymentIntentUpdateParams) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id]), params)
+    }
+
+    /// Confirms that the customer intends to pay with the attached payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+    pub fn confirm(client: &Client, payment_intent_id: &str, params: PaymentIntentConfirmParams) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id, "confirm"]), params)
+    }
+
+    /// Captures the funds of a payment intent with status `requires_capture`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/capture.
+    pub fn capture(client: &Client, payment_intent_id: &str, params: PaymentIntentCaptureParams) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id, "capture"]), params)
+    }
+
+    /// Increases the authorized amount of a payment intent.
+    ///
+    /// This is only available for certain card brands, and only when the payment intent is
+    /// in the `requires_capture` status and was created with `request_incremental_authorization`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/increment_authorization.
+    pub fn increment_authorization(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentIncrementalAuthorizationParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id, "increment_authorization"]), params)
+    }
+
+    /// Verifies the micro-deposits sent to a customer's bank account to confirm a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/verify_microdeposits.
+    pub fn verify_microdeposits(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentVerifyMicrodepositsParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id, "verify_microdeposits"]), params)
+    }
+
+    /// Cancels a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+    pub fn cancel(client: &Client, payment_intent_id: &str, params: PaymentIntentCancelParams) -> Result<PaymentIntent, Error> {
+        client.post(&Client::path_for(&["payment_intents", payment_intent_id, "cancel"]), params)
+    }
+
+    /// Lists all payment intents.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/list.
+    pub fn list(client: &Client, params: PaymentIntentListParams) -> Result<List<PaymentIntent>, Error> {
+        client.get(&format!("/payment_intents?{}", qs::to_string(&params)?))
+    }
+
+    /// Lists all payment intents, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: PaymentIntentListParams, max_items: usize) -> Result<Vec<PaymentIntent>, Error> {
+        client.list_all("/payment_intents", params, max_items)
+    }
+
+    /// Returns the latest charge of this payment intent, fetching it if it wasn't expanded.
+    pub fn latest_charge(&self, client: &Client) -> Result<Option<Linked<Charge>>, Error> {
+        match self.latest_charge {
+            Some(ref charge) => charge.get_or_fetch(|id| Charge::retrieve(client, id)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Object for PaymentIntent {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn poll_until() {
+        use super::{PaymentIntent, PaymentIntentStatus};
+        use client::Client;
+        use error::Error;
+        use mock::{ok, serve};
+        use std::time::Duration;
+
+        let intent = |status: &str| format!(
+            r#"{{"id":"pi_123","object":"payment_intent","amount":2000,"amount_capturable":0,"amount_received":0,"capture_method":"manual","confirmation_method":"automatic","created":1500000000,"currency":"usd","livemode":false,"metadata":{{}},"payment_method_types":["card"],"status":"{}"}}"#,
+            status
+        );
+        let api_base = |statuses: Vec<&str>| serve(ok(statuses.into_iter().map(|status| intent(status)).collect())).0;
+        let interval = Duration::from_millis(1);
+
+        // Polling stops at a target status
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["requires_action", "processing", "requires_capture"]));
+        let settled = PaymentIntent::poll_until(&client, "pi_123", &[PaymentIntentStatus::RequiresCapture], interval, 5).unwrap();
+        assert_eq!(settled.status, PaymentIntentStatus::RequiresCapture);
+        assert_eq!(client.request_count(), 3);
+
+        // ... or a terminal one
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["requires_action", "canceled"]));
+        let settled = PaymentIntent::poll_until(&client, "pi_123", &[], interval, 5).unwrap();
+        assert_eq!(settled.status, PaymentIntentStatus::Canceled);
+
+        // ... but not at another status, eg. after a failed authentication
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["processing", "requires_payment_method", "succeeded"]));
+        let settled = PaymentIntent::poll_until(&client, "pi_123", &[PaymentIntentStatus::Succeeded], interval, 5).unwrap();
+        assert_eq!(settled.status, PaymentIntentStatus::Succeeded);
+        assert_eq!(client.request_count(), 3);
+
+        // ... and retrieves it at least once
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["requires_action"]));
+        match PaymentIntent::poll_until(&client, "pi_123", &[PaymentIntentStatus::Succeeded], interval, 0) {
+            Err(Error::Timeout) => assert_eq!(client.request_count(), 1),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+
+        // ... or times out after the given number of attempts
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["requires_action", "requires_action"]));
+        match PaymentIntent::poll_until(&client, "pi_123", &[PaymentIntentStatus::Succeeded], interval, 2) {
+            Err(Error::Timeout) => assert_eq!(client.request_count(), 2),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn automatic_payment_methods_with_types() {
+        use super::{AutomaticPaymentMethods, PaymentIntent, PaymentIntentParams};
+        use client::Client;
+        use error::Error;
+
+        let client = Client::new("sk_test_123");
+        let mut params = PaymentIntentParams::default();
+        params.automatic_payment_methods = Some(AutomaticPaymentMethods { enabled: true, allow_redirects: None });
+        params.payment_method_types = Some(vec!["card"]);
+        match PaymentIntent::create(&client, params) {
+            Err(Error::Unsupported(_)) => assert_eq!(client.request_count(), 0),
+            other => panic!("expected an unsupported error, got {:?}", other),
+        }
+    }
+}
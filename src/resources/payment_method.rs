@@ -0,0 +1,149 @@
+use error::Error;
+use client::Client;
+use params::{ListParams, Metadata, Timestamp};
+use resources::Address;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serde_json as json;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct BillingDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+/// The card details of a payment method created inline.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/create#create_payment_method-card.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodCardParams<'a> {
+    pub number: &'a str,
+    pub exp_month: u32,
+    pub exp_year: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvc: Option<&'a str>,
+
+    // NOTE: Serialized by `PaymentMethodData`, beside the card details
+    #[serde(skip)]
+    pub billing_details: Option<BillingDetails>,
+}
+
+/// The SEPA Direct Debit details of a payment method created inline.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/create#create_payment_method-sepa_debit.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodSepaDebitParams<'a> {
+    pub iban: &'a str,
+
+    // NOTE: Serialized by `PaymentMethodData`, beside the bank account details;
+    //   SEPA Direct Debit payments require the billing name and email
+    #[serde(skip)]
+    pub billing_details: Option<BillingDetails>,
+}
+
+/// The details of a payment method to create inline (eg. when creating a payment intent).
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create#create_payment_intent-payment_method_data.
+pub enum PaymentMethodData<'a> {
+    Card(PaymentMethodCardParams<'a>),
+    SepaDebit(PaymentMethodSepaDebitParams<'a>),
+}
+
+impl<'a> Serialize for PaymentMethodData<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // NOTE: Stripe expects the details nested under a key matching the type,
+        //   eg. `payment_method_data[type]=card&payment_method_data[card][number]=..`
+        let (method_type, billing_details) = match *self {
+            PaymentMethodData::Card(ref card) => ("card", &card.billing_details),
+            PaymentMethodData::SepaDebit(ref sepa_debit) => ("sepa_debit", &sepa_debit.billing_details),
+        };
+        let mut state = serializer.serialize_struct("PaymentMethodData", 3)?;
+        state.serialize_field("type", method_type)?;
+        if let Some(ref billing_details) = *billing_details {
+            state.serialize_field("billing_details", billing_details)?;
+        }
+        match *self {
+            PaymentMethodData::Card(ref card) => state.serialize_field("card", card)?,
+            PaymentMethodData::SepaDebit(ref sepa_debit) => state.serialize_field("sepa_debit", sepa_debit)?,
+        }
+        state.end()
+    }
+}
+
+#[derive(Serialize)]
+struct AttachParams<'a> {
+    customer: &'a str,
+}
+
+/// The set of parameters that can be used when listing a customer's payment methods.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/customer_list.
+#[derive(Default, Serialize)]
+pub struct PaymentMethodListParams<'a> {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<&'a str>, // (card, sepa_debit, us_bank_account, ..)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for PaymentMethodListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a Stripe payment method.
+///
+/// For more details see https://stripe.com/docs/api/payment_methods/object.
+#[derive(Debug, Deserialize)]
+pub struct PaymentMethod {
+    pub id: String,
+    pub object: String,
+    pub billing_details: BillingDetails,
+    #[serde(default)]
+    pub card: Option<json::Value>,
+    pub created: Timestamp,
+    pub customer: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(rename = "type")]
+    pub payment_method_type: String, // (card, sepa_debit, us_bank_account, ..)
+}
+
+impl PaymentMethod {
+    /// Retrieves the details of a payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/retrieve.
+    pub fn retrieve(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.get_at(&["payment_methods", payment_method_id])
+    }
+
+    /// Attaches a payment method to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/attach.
+    pub fn attach(client: &Client, payment_method_id: &str, customer_id: &str) -> Result<PaymentMethod, Error> {
+        let params = AttachParams { customer: customer_id };
+        client.post(&Client::path_for(&["payment_methods", payment_method_id, "attach"]), params)
+    }
+
+    /// Detaches a payment method from its customer.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_methods/detach.
+    pub fn detach(client: &Client, payment_method_id: &str) -> Result<PaymentMethod, Error> {
+        client.post_empty(&Client::path_for(&["payment_methods", payment_method_id, "detach"]))
+    }
+}
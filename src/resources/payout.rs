@@ -1,5 +1,45 @@
+use error::Error;
+use client::Client;
 use params::{Metadata, Timestamp};
-use resources::Currency;
+use resources::{BalanceTransaction, BalanceTransactionListParams, Currency};
+use std::collections::BTreeMap;
+
+/// The set of parameters that can be used when creating a payout.
+///
+/// For more details see https://stripe.com/docs/api#create_payout.
+#[derive(Serialize)]
+pub struct PayoutParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>, // the id of a bank account or card
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'a str>, // (standard, instant)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<&'a str>, // (card, bank_account, fpx)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// The status of a payout.
+///
+/// For more details see https://stripe.com/docs/api/payouts/object#payout_object-status.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutStatus {
+    Canceled,
+    Failed,
+    InTransit,
+    Paid,
+    Pending,
+    #[serde(other)]
+    Other,
+}
 
 /// The resource representing a Stripe payout.
 ///
@@ -23,7 +63,232 @@ pub struct Payout {
     pub method: String, // (standard, instant)
     pub source_type: String, // (card, bank_account, bitcoin_receiver, alipay_account)
     pub statement_descriptor: Option<String>,
-    pub status: String,
+    pub status: PayoutStatus,
     #[serde(rename = "type")]
     pub payout_type: String, // (bank_account, card)
 }
+
+impl Payout {
+    /// Creates a new payout.
+    ///
+    /// To pay out a connected account's balance, use a client with its `stripe_account` param set.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_payout.
+    pub fn create(client: &Client, params: PayoutParams) -> Result<Payout, Error> {
+        client.post("/payouts", params)
+    }
+
+    /// Retrieves the details of a payout.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_payout.
+    pub fn retrieve(client: &Client, payout_id: &str) -> Result<Payout, Error> {
+        client.get_at(&["payouts", payout_id])
+    }
+
+    /// Lists all the balance transactions paid out by a payout, following pagination,
+    /// failing if there are more than `max_items`.
+    ///
+    /// This includes the payout's own (negative) balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api/balance_transactions/list#balance_transaction_list-payout.
+    pub fn list_balance_transactions(client: &Client, payout_id: &str, max_items: usize) -> Result<Vec<BalanceTransaction>, Error> {
+        let params = BalanceTransactionListParams { payout: Some(payout_id), ..Default::default() };
+        BalanceTransaction::list_all(client, params, max_items)
+    }
+
+    /// Cancels a payout which is still pending, returning the canceled payout.
+    ///
+    /// Payouts which aren't pending can't be canceled, so for those this fails with
+    /// `Error::InvalidOperation` without making a request.
+    ///
+    /// For more details see https://stripe.com/docs/api/payouts/cancel.
+    pub fn cancel(&self, client: &Client) -> Result<Payout, Error> {
+        if self.status != PayoutStatus::Pending {
+            return Err(Error::InvalidOperation("can only cancel pending payouts"));
+        }
+        client.post_empty(&Client::path_for(&["payouts", &self.id, "cancel"]))
+    }
+}
+
+/// The summed amounts of a set of balance transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReconciliationTotals {
+    pub count: usize,
+    pub gross: i64,
+    pub fee: i64,
+    pub net: i64,
+}
+
+impl ReconciliationTotals {
+    fn add(&mut self, txn: &BalanceTransaction) {
+        self.count += 1;
+        self.gross += txn.amount;
+        self.fee += txn.fee;
+        self.net += txn.net;
+    }
+}
+
+/// A summary of the balance transactions paid out by a payout, for reconciliation.
+///
+/// The payout's own balance transaction is left out of the totals, so when
+/// the payout is fully accounted for the net total equals the payout amount.
+#[derive(Debug)]
+pub struct PayoutReconciliation {
+    pub payout_amount: i64,
+    pub currency: Currency,
+    pub total: ReconciliationTotals,
+    pub by_type: BTreeMap<String, ReconciliationTotals>,
+
+    /// The ids of transactions in a different currency than the payout,
+    /// which are left out of the totals because they can't be summed with it.
+    pub foreign_currency: Vec<String>,
+}
+
+impl PayoutReconciliation {
+    /// Summarizes the balance transactions of a payout (eg. from `Payout::list_balance_transactions`).
+    pub fn new(payout: &Payout, transactions: &[BalanceTransaction]) -> PayoutReconciliation {
+        let mut reconciliation = PayoutReconciliation {
+            payout_amount: payout.amount as i64,
+            currency: payout.currency.clone(),
+            total: ReconciliationTotals::default(),
+            by_type: BTreeMap::new(),
+            foreign_currency: Vec::new(),
+        };
+        for txn in transactions {
+            if txn.id == payout.balance_transaction {
+                continue;
+            }
+            if txn.currency != payout.currency {
+                reconciliation.foreign_currency.push(txn.id.clone());
+                continue;
+            }
+            reconciliation.total.add(txn);
+            reconciliation.by_type.entry(txn.transaction_type.clone()).or_insert_with(ReconciliationTotals::default).add(txn);
+        }
+        reconciliation
+    }
+
+    /// Returns the payout amount less the net total of its transactions (zero when they match).
+    pub fn discrepancy(&self) -> i64 {
+        self.payout_amount - self.total.net
+    }
+
+    /// Returns true if every transaction could be summed and their net total matches the payout amount.
+    pub fn is_balanced(&self) -> bool {
+        self.foreign_currency.is_empty() && self.discrepancy() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn create_for_connected_account() {
+        use super::{Payout, PayoutParams};
+        use client::{Client, Params};
+        use error::Error;
+        use mock::serve;
+        use resources::Currency;
+
+        let (api_base, server) = serve(vec![
+            ("200 OK", r#"{
+                "id": "po_123", "object": "payout", "amount": 1100, "arrival_date": 1500000000,
+                "balance_transaction": "txn_123", "created": 1500000000, "currency": "usd",
+                "description": "STRIPE PAYOUT", "livemode": false, "metadata": {}, "method": "standard",
+                "source_type": "card", "status": "pending", "type": "bank_account"
+            }"#.to_string()),
+            ("400 Bad Request", r#"{"error":{"type":"invalid_request_error","param":"currency","message":"Invalid currency: xyz"}}"#.to_string()),
+        ]);
+
+        let platform = Client::new("sk_test_123").with_api_base(api_base);
+        let client = platform.with(Params { stripe_account: Some("acct_123".to_string()), ..Params::default() });
+        let params = PayoutParams {
+            amount: 1100,
+            currency: Currency::USD,
+            description: None,
+            destination: None,
+            metadata: None,
+            method: None,
+            source_type: None,
+            statement_descriptor: None,
+        };
+        let payout = Payout::create(&client, params).unwrap();
+        assert_eq!(payout.id, "po_123");
+
+        let params = PayoutParams {
+            amount: 1100,
+            currency: Currency::Other("xyz".to_string()),
+            description: None,
+            destination: None,
+            metadata: None,
+            method: None,
+            source_type: None,
+            statement_descriptor: None,
+        };
+        match Payout::create(&client, params) {
+            Err(Error::Stripe(ref err)) => assert_eq!(err.param.as_ref().map(|param| param.as_str()), Some("currency")),
+            other => panic!("expected an invalid request error, got {:?}", other),
+        }
+
+        for request in server.join().unwrap() {
+            assert!(request.starts_with("POST /v1/payouts "));
+            assert!(request.contains("Stripe-Account: acct_123\r\n"));
+        }
+    }
+
+    #[test]
+    fn cancel_paid() {
+        use super::Payout;
+        use client::Client;
+        use error::Error;
+        use mock::UNREACHABLE;
+        use serde_json as json;
+
+        let payout: Payout = json::from_str(r#"{
+            "id": "po_123",
+            "object": "payout",
+            "amount": 1000,
+            "arrival_date": 1504233902,
+            "balance_transaction": "txn_123",
+            "created": 1504233902,
+            "currency": "usd",
+            "description": "STRIPE PAYOUT",
+            "livemode": false,
+            "metadata": {},
+            "method": "standard",
+            "source_type": "card",
+            "status": "paid",
+            "type": "bank_account"
+        }"#).unwrap();
+
+        let client = Client::new("sk_test_123").with_api_base(UNREACHABLE);
+        match payout.cancel(&client) {
+            Err(Error::InvalidOperation(msg)) => assert_eq!(msg, "can only cancel pending payouts"),
+            other => panic!("expected an invalid operation error, got {:?}", other.map(|payout| payout.id)),
+        }
+    }
+
+    #[test]
+    fn list_balance_transactions() {
+        use super::Payout;
+        use client::Client;
+        use mock::{ok, paths, serve};
+
+        let txn = |id: &str| format!(
+            r#"{{"id":"{}","object":"balance_transaction","amount":1000,"available_on":1500000000,"created":1500000000,"currency":"usd","description":null,"fee":59,"fee_details":[],"net":941,"source":{{"object":"charge","id":"ch_123"}},"status":"available","type":"charge"}}"#,
+            id
+        );
+        let (api_base, server) = serve(ok(vec![
+            format!(r#"{{"data":[{},{}],"has_more":true,"url":"/v1/balance_transactions"}}"#, txn("txn_1"), txn("txn_2")),
+            format!(r#"{{"data":[{}],"has_more":false,"url":"/v1/balance_transactions"}}"#, txn("txn_3")),
+        ]));
+
+        let client = Client::new("sk_test_123").with_api_base(api_base);
+        let transactions = Payout::list_balance_transactions(&client, "po_123", 100).unwrap();
+        let ids: Vec<_> = transactions.iter().map(|txn| txn.id.as_str()).collect();
+        assert_eq!(ids, vec!["txn_1", "txn_2", "txn_3"]);
+        assert_eq!(paths(&server.join().unwrap()), vec![
+            "/v1/balance_transactions?limit=100&payout=po_123",
+            "/v1/balance_transactions?limit=100&payout=po_123&starting_after=txn_2",
+        ]);
+    }
+}
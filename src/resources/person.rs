@@ -0,0 +1,204 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata, Object, Timestamp};
+use resources::{Address, Deleted, Requirements};
+use serde_json as json;
+use serde_qs as qs;
+
+/// A date of birth, as used by persons.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DateOfBirth {
+    pub day: u32,
+    pub month: u32,
+    pub year: u32,
+}
+
+/// How a person is related to an account.
+///
+/// For more details see https://stripe.com/docs/api/persons/object#person_object-relationship.
+#[derive(Debug, Default, Deserialize)]
+pub struct Relationship {
+    #[serde(default)]
+    pub director: bool,
+    #[serde(default)]
+    pub executive: bool,
+    #[serde(default)]
+    pub owner: bool,
+    pub percent_ownership: Option<f64>,
+    #[serde(default)]
+    pub representative: bool,
+    pub title: Option<String>,
+}
+
+/// The set of parameters that can be used when setting how a person is related to an account.
+///
+/// For more details see https://stripe.com/docs/api/persons/create#create_person-relationship.
+#[derive(Default, Serialize)]
+pub struct RelationshipParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub director: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_ownership: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when creating a person.
+///
+/// For more details see https://stripe.com/docs/api/persons/create.
+#[derive(Default, Serialize)]
+pub struct PersonCreateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<DateOfBirth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<RelationshipParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a person.
+///
+/// For more details see https://stripe.com/docs/api/persons/update.
+#[derive(Default, Serialize)]
+pub struct PersonUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<DateOfBirth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<RelationshipParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing persons.
+///
+/// For more details see https://stripe.com/docs/api/persons/list.
+#[derive(Default, Serialize)]
+pub struct PersonListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<RelationshipParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for PersonListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a person associated with a Stripe account (eg. an owner or director).
+///
+/// For more details see https://stripe.com/docs/api/persons/object.
+#[derive(Debug, Deserialize)]
+pub struct Person {
+    pub id: String,
+    pub object: String,
+    pub account: String,
+    pub address: Option<Address>,
+    pub created: Option<Timestamp>,
+    pub dob: Option<DateOfBirth>,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub id_number_provided: bool, // NOTE: the id number itself is never returned
+    pub last_name: Option<String>,
+    pub metadata: Metadata,
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub relationship: Relationship,
+    #[serde(default)]
+    pub requirements: Option<Requirements>,
+    #[serde(default)]
+    pub ssn_last_4_provided: bool, // NOTE: the last 4 digits themselves are never returned
+    pub verification: Option<json::Value>,
+}
+
+impl Person {
+    /// Creates a new person associated with an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/create.
+    pub fn create(client: &Client, account_id: &str, params: PersonCreateParams) -> Result<Person, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "persons"]), params)
+    }
+
+    /// Retrieves the details of a person associated with an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/retrieve.
+    pub fn retrieve(client: &Client, account_id: &str, person_id: &str) -> Result<Person, Error> {
+        client.get_at(&["accounts", account_id, "persons", person_id])
+    }
+
+    /// Updates a person's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/update.
+    pub fn update(client: &Client, account_id: &str, person_id: &str, params: PersonUpdateParams) -> Result<Person, Error> {
+        client.post(&Client::path_for(&["accounts", account_id, "persons", person_id]), params)
+    }
+
+    /// Deletes a person's relationship to an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/delete.
+    pub fn delete(client: &Client, account_id: &str, person_id: &str) -> Result<Deleted, Error> {
+        client.delete(&Client::path_for(&["accounts", account_id, "persons", person_id]))
+    }
+
+    /// Lists the persons associated with an account.
+    ///
+    /// For more details see https://stripe.com/docs/api/persons/list.
+    pub fn list(client: &Client, account_id: &str, params: PersonListParams) -> Result<List<Person>, Error> {
+        client.get(&format!("{}?{}", Client::path_for(&["accounts", account_id, "persons"]), qs::to_string(&params)?))
+    }
+
+    /// Lists all persons associated with an account, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, account_id: &str, params: PersonListParams, max_items: usize) -> Result<Vec<Person>, Error> {
+        client.list_all(&Client::path_for(&["accounts", account_id, "persons"]), params, max_items)
+    }
+}
+
+impl Object for Person {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -1,7 +1,7 @@
 use error::Error;
 use client::Client;
 use params::{Metadata, Timestamp};
-use resources::{Currency, Deleted};
+use resources::{Currency, Deleted, Recurring};
 
 /// The set of parameters that can be used when creating or updating a plan.
 ///
@@ -15,12 +15,11 @@ pub struct PlanParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval: Option<&'a str>, // (day, week, month, year)
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<&'a str>,
-
+    #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval_count: Option<u64>,
+    pub recurring: Option<Recurring>, // required when creating a plan
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -38,8 +37,8 @@ pub struct Plan {
     pub amount: u64,
     pub created: Timestamp,
     pub currency: Currency,
-    pub interval: String, // (day, week, month, year)
-    pub interval_count: u64,
+    #[serde(flatten)]
+    pub recurring: Recurring,
     pub livemode: bool,
     pub metadata: Metadata,
     pub nickname: String,
@@ -59,20 +58,20 @@ impl Plan {
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_plan.
     pub fn retrieve(client: &Client, plan_id: &str) -> Result<Plan, Error> {
-        client.get(&format!("/plans/{}", plan_id))
+        client.get_at(&["plans", plan_id])
     }
 
     /// Updates a plan's properties.
     ///
     /// For more details see https://stripe.com/docs/api#update_plan.
     pub fn update(client: &Client, plan_id: &str, params: PlanParams) -> Result<Plan, Error> {
-        client.post(&format!("/plans/{}", plan_id), params)
+        client.post(&Client::path_for(&["plans", plan_id]), params)
     }
 
     /// Deletes a plan.
     ///
     /// For more details see https://stripe.com/docs/api#delete_plan.
     pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted, Error> {
-        client.delete(&format!("/plans/{}", plan_id))
+        client.delete(&Client::path_for(&["plans", plan_id]))
     }
 }
@@ -0,0 +1,217 @@
+use error::Error;
+use client::Client;
+use params::{deserialize_lenient, List, ListParams, Metadata, Object, Timestamp};
+use resources::{Currency, Recurring};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_qs as qs;
+
+/// How a price computes the amount to charge for a quantity.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum BillingScheme {
+    PerUnit,
+    Tiered,
+
+    /// A billing scheme not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for BillingScheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, BillingScheme::deserialize, BillingScheme::Other)
+    }
+}
+
+impl Serialize for BillingScheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            BillingScheme::Other(ref value) => serializer.serialize_str(value),
+            _ => BillingScheme::serialize(self, serializer),
+        }
+    }
+}
+
+/// Whether a tiered price charges each unit at its own tier (graduated) or all units at one tier (volume).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum TiersMode {
+    Graduated,
+    Volume,
+
+    /// A tiers mode not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for TiersMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, TiersMode::deserialize, TiersMode::Other)
+    }
+}
+
+impl Serialize for TiersMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            TiersMode::Other(ref value) => serializer.serialize_str(value),
+            _ => TiersMode::serialize(self, serializer),
+        }
+    }
+}
+
+/// The upper bound of a price tier.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UpTo {
+    /// The last tier, which has no upper bound.
+    Inf,
+    Max(u64),
+}
+
+impl Serialize for UpTo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            UpTo::Inf => serializer.serialize_str("inf"),
+            UpTo::Max(value) => serializer.serialize_u64(value),
+        }
+    }
+}
+
+/// A tier of a tiered price, as given when creating the price.
+#[derive(Debug, Serialize)]
+pub struct PriceTierParams {
+    pub up_to: UpTo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// A tier of a tiered price.
+#[derive(Debug, Deserialize)]
+pub struct PriceTier {
+    pub flat_amount: Option<i64>,
+    pub unit_amount: Option<i64>,
+    pub up_to: Option<u64>, // None for the last tier
+}
+
+/// The set of parameters that can be used when creating or updating a price.
+///
+/// For more details see https://stripe.com/docs/api/prices/create and https://stripe.com/docs/api/prices/update.
+#[derive(Default, Serialize)]
+pub struct PriceParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<BillingScheme>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>, // required when creating a price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lookup_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>, // required when creating a price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<Recurring>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<PriceTierParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// The set of parameters that can be used when listing prices.
+///
+/// For more details see https://stripe.com/docs/api/prices/list.
+#[derive(Default, Serialize)]
+pub struct PriceListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_type: Option<&'a str>, // (one_time, recurring)
+}
+
+impl<'a> ListParams for PriceListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a Stripe price.
+///
+/// For more details see https://stripe.com/docs/api/prices/object.
+#[derive(Debug, Deserialize)]
+pub struct Price {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub billing_scheme: BillingScheme,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub lookup_key: Option<String>,
+    pub metadata: Metadata,
+    pub nickname: Option<String>,
+    pub product: String,
+    pub recurring: Option<Recurring>,
+    #[serde(default)]
+    pub tiers: Option<Vec<PriceTier>>, // only present when expanded
+    pub tiers_mode: Option<TiersMode>,
+    #[serde(rename = "type")]
+    pub price_type: String, // (one_time, recurring)
+    pub unit_amount: Option<i64>,
+}
+
+impl Price {
+    /// Creates a new price.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/create.
+    pub fn create(client: &Client, params: PriceParams) -> Result<Price, Error> {
+        client.post("/prices", params)
+    }
+
+    /// Retrieves the details of a price.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/retrieve.
+    pub fn retrieve(client: &Client, price_id: &str) -> Result<Price, Error> {
+        client.get_at(&["prices", price_id])
+    }
+
+    /// Updates a price's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/update.
+    pub fn update(client: &Client, price_id: &str, params: PriceParams) -> Result<Price, Error> {
+        client.post(&Client::path_for(&["prices", price_id]), params)
+    }
+
+    /// Lists all prices.
+    ///
+    /// For more details see https://stripe.com/docs/api/prices/list.
+    pub fn list(client: &Client, params: PriceListParams) -> Result<List<Price>, Error> {
+        client.get(&format!("/prices?{}", qs::to_string(&params)?))
+    }
+}
+
+impl Object for Price {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,34 @@
+use params::deserialize_lenient;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// How prorations are handled when a subscription's billing changes partway through a period.
+///
+/// This replaces the older `prorate` boolean param.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/prorations.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum ProrationBehavior {
+    AlwaysInvoice,
+    CreateProrations,
+    None,
+
+    /// A proration behavior not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for ProrationBehavior {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, ProrationBehavior::deserialize, ProrationBehavior::Other)
+    }
+}
+
+impl Serialize for ProrationBehavior {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            ProrationBehavior::Other(ref value) => serializer.serialize_str(value),
+            _ => ProrationBehavior::serialize(self, serializer),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use params::deserialize_lenient;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The frequency at which a recurring price or plan bills.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum Interval {
+    Day,
+    Week,
+    Month,
+    Year,
+
+    /// An interval not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, Interval::deserialize, Interval::Other)
+    }
+}
+
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Interval::Other(ref value) => serializer.serialize_str(value),
+            _ => Interval::serialize(self, serializer),
+        }
+    }
+}
+
+/// Whether a recurring price bills for a fixed quantity or for reported usage.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum UsageType {
+    Licensed,
+    Metered,
+
+    /// A usage type not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl UsageType {
+    fn is_licensed(&self) -> bool {
+        *self == UsageType::Licensed
+    }
+}
+
+impl Default for UsageType {
+    fn default() -> Self {
+        UsageType::Licensed
+    }
+}
+
+impl<'de> Deserialize<'de> for UsageType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, UsageType::deserialize, UsageType::Other)
+    }
+}
+
+impl Serialize for UsageType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            UsageType::Other(ref value) => serializer.serialize_str(value),
+            _ => UsageType::serialize(self, serializer),
+        }
+    }
+}
+
+/// How the usage reported during a period is aggregated for metered billing.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum AggregateUsage {
+    LastDuringPeriod,
+    LastEver,
+    Max,
+    Sum,
+
+    /// An aggregation not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for AggregateUsage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, AggregateUsage::deserialize, AggregateUsage::Other)
+    }
+}
+
+impl Serialize for AggregateUsage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            AggregateUsage::Other(ref value) => serializer.serialize_str(value),
+            _ => AggregateUsage::serialize(self, serializer),
+        }
+    }
+}
+
+/// The recurring components of a plan or price (eg. "every 3 months").
+///
+/// The usage type is only serialized when it isn't the default `Licensed`,
+/// since some endpoints (eg. a subscription item's `price_data`) don't accept it.
+///
+/// For more details see https://stripe.com/docs/api/prices/object#price_object-recurring.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Recurring {
+    pub interval: Interval,
+    pub interval_count: u64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "UsageType::is_licensed")]
+    pub usage_type: UsageType,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<AggregateUsage>,
+}
+
+/// The recurring components of a price, shared with plans and subscription items.
+pub type PriceRecurring = Recurring;
+
+impl Recurring {
+    /// Creates a licensed recurring interval of `interval_count` intervals (eg. every 3 months).
+    pub fn new(interval: Interval, interval_count: u64) -> Recurring {
+        Recurring {
+            interval: interval,
+            interval_count: interval_count,
+            usage_type: UsageType::Licensed,
+            aggregate_usage: None,
+        }
+    }
+}
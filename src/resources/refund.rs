@@ -1,6 +1,27 @@
-use params::{Metadata, Timestamp};
+use error::Error;
+use client::Client;
+use params::{Metadata, Object, Timestamp};
 use resources::Currency;
 
+/// The set of parameters that can be used when refunding a charge.
+///
+/// For more details see https://stripe.com/docs/api#create_refund.
+#[derive(Default, Serialize)]
+pub struct RefundParams<'a> {
+    pub charge: &'a str, // this is a required param
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>, // NOTE: if None, the whole charge is refunded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'a str>, // (duplicate, fraudulent, requested_by_customer)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
 /// The resource representing a Stripe refund.
 ///
 /// For more details see https://stripe.com/docs/api#refunds.
@@ -15,8 +36,25 @@ pub struct Refund {
     pub currency: Currency,
     pub failure_balance_transaction: Option<String>,
     pub failure_reason: Option<String>,
+    #[serde(default)]
+    pub livemode: Option<bool>, // not included by every API version
     pub metadata: Metadata,
     pub reason: Option<String>, // (duplicate, fraudulent, requested_by_customer)
     pub receipt_number: Option<String>,
     pub status: String, // (succeeded, pending, failed, cancelled)
 }
+
+impl Refund {
+    /// Refunds a charge, in whole or in part.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_refund.
+    pub fn create(client: &Client, params: RefundParams) -> Result<Refund, Error> {
+        client.post("/refunds", params)
+    }
+}
+
+impl Object for Refund {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
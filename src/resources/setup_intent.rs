@@ -0,0 +1,134 @@
+use error::Error;
+use client::Client;
+use params::{Metadata, Timestamp};
+use resources::{ApiError, AutomaticPaymentMethods, NextAction};
+
+/// The set of parameters that can be used when creating a setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/create.
+#[derive(Default, Serialize)]
+pub struct SetupIntentParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_payment_methods: Option<AutomaticPaymentMethods>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<&'a str>, // (on_session, off_session)
+}
+
+/// The set of parameters that can be used when confirming a setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/confirm.
+#[derive(Default, Serialize)]
+pub struct SetupIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when verifying micro-deposits on a setup intent.
+///
+/// Provide either the two deposit `amounts` or the `descriptor_code`, but not both.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/verify_microdeposits.
+#[derive(Default, Serialize)]
+pub struct SetupIntentVerifyMicrodepositsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<Vec<i64>>, // in cents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_code: Option<String>, // eg. "SM11AA"
+}
+
+/// The status of a setup intent.
+///
+/// For more details see https://stripe.com/docs/payments/intents#intent-statuses.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupIntentStatus {
+    Canceled,
+    Processing,
+    RequiresAction,
+    RequiresConfirmation,
+    RequiresPaymentMethod,
+    Succeeded,
+    #[serde(other)]
+    Other,
+}
+
+/// The resource representing a Stripe setup intent.
+///
+/// For more details see https://stripe.com/docs/api/setup_intents/object.
+#[derive(Debug, Deserialize)]
+pub struct SetupIntent {
+    pub id: String,
+    pub object: String,
+    #[serde(default)]
+    pub automatic_payment_methods: Option<AutomaticPaymentMethods>,
+    pub cancellation_reason: Option<String>,
+    pub client_secret: Option<String>,
+    pub created: Timestamp,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub last_setup_error: Option<ApiError>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub next_action: Option<NextAction>,
+    pub payment_method: Option<String>,
+    pub payment_method_types: Vec<String>,
+    pub status: SetupIntentStatus,
+    pub usage: String, // (on_session, off_session)
+}
+
+impl SetupIntent {
+    /// Creates a new setup intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/create.
+    pub fn create(client: &Client, params: SetupIntentParams) -> Result<SetupIntent, Error> {
+        client.post("/setup_intents", params)
+    }
+
+    /// Retrieves the details of a setup intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/retrieve.
+    pub fn retrieve(client: &Client, setup_intent_id: &str) -> Result<SetupIntent, Error> {
+        client.get_at(&["setup_intents", setup_intent_id])
+    }
+
+    /// Confirms that the customer intends to set up the attached payment method.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/confirm.
+    pub fn confirm(client: &Client, setup_intent_id: &str, params: SetupIntentConfirmParams) -> Result<SetupIntent, Error> {
+        client.post(&Client::path_for(&["setup_intents", setup_intent_id, "confirm"]), params)
+    }
+
+    /// Verifies the micro-deposits sent to the bank account being set up.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/verify_microdeposits.
+    pub fn verify_microdeposits(
+        client: &Client,
+        setup_intent_id: &str,
+        params: SetupIntentVerifyMicrodepositsParams,
+    ) -> Result<SetupIntent, Error> {
+        client.post(&Client::path_for(&["setup_intents", setup_intent_id, "verify_microdeposits"]), params)
+    }
+
+    /// Cancels a setup intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/setup_intents/cancel.
+    pub fn cancel(client: &Client, setup_intent_id: &str) -> Result<SetupIntent, Error> {
+        client.post_empty(&Client::path_for(&["setup_intents", setup_intent_id, "cancel"]))
+    }
+}
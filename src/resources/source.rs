@@ -50,6 +50,9 @@ pub enum Source {
     // BitcoinReceiver(...),
     #[serde(rename = "card")]
     Card(Card),
+    // NOTE: Source types not yet known to this crate are dropped rather than failing
+    #[serde(other)]
+    Other,
 }
 
 impl Source {
@@ -58,10 +61,10 @@ impl Source {
     }
 
     pub fn get(client: &Client, source_id: &str) -> Result<Source, Error> {
-        client.get(&format!("/sources/{}", source_id))
+        client.get_at(&["sources", source_id])
     }
 
     pub fn update(client: &Client, source_id: &str, params: SourceParams) -> Result<Source, Error> {
-        client.post(&format!("/source/{}", source_id), params)
+        client.post(&Client::path_for(&["source", source_id]), params)
     }
 }
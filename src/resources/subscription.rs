@@ -1,7 +1,9 @@
 use error::Error;
 use client::Client;
-use resources::{Discount, Plan};
-use params::{List, Metadata, Timestamp};
+use resources::{BillingCycleAnchor, Currency, Discount, Invoice, InvoiceLineItem, Plan, Price, ProrationBehavior, Recurring, SubscriptionItemParams};
+use params::{deserialize_lenient, List, ListParams, Metadata, Object, RangeQuery, Timestamp};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeStruct;
 use serde_qs as qs;
 
 #[derive(Default, Serialize)]
@@ -10,15 +12,171 @@ pub struct CancelParams {
     pub at_period_end: Option<bool>,
 }
 
-#[derive(Serialize, Debug)]
+/// An item of a subscription being created or updated.
+///
+/// When updating a subscription, set `id` to change an existing item (or to remove it,
+/// with `deleted`); items without an `id` are added to the subscription.
+///
+/// For more details see https://stripe.com/docs/api/subscriptions/update#update_subscription-items.
+#[derive(Default, Serialize, Debug)]
 pub struct ItemParams<'a> {
-    pub plan: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<ItemPriceData<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
 }
 
+/// An inline price for a subscription item, used instead of an existing plan or price.
+///
+/// For more details see https://stripe.com/docs/api/subscriptions/create#create_subscription-items-price_data.
+#[derive(Serialize, Debug)]
+pub struct ItemPriceData<'a> {
+    pub currency: Currency,
+    pub product: &'a str,
+    pub recurring: Recurring,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// What happens to invoices created while a subscription's payment collection is paused.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/pause.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum PauseCollectionBehavior {
+    KeepAsDraft,
+    MarkUncollectible,
+    Void,
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for PauseCollectionBehavior {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, PauseCollectionBehavior::deserialize, PauseCollectionBehavior::Other)
+    }
+}
+
+impl Serialize for PauseCollectionBehavior {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            PauseCollectionBehavior::Other(ref value) => serializer.serialize_str(value),
+            _ => PauseCollectionBehavior::serialize(self, serializer),
+        }
+    }
+}
+
+/// The pause_collection settings of a subscription.
+///
+/// When serialized with neither a behavior nor a resumes_at, this encodes as an
+/// empty string (ie. `pause_collection=`) which resumes collection.
+///
+/// For more details see https://stripe.com/docs/api/subscriptions/update#update_subscription-pause_collection.
+#[derive(Debug, Default, Deserialize)]
+pub struct SubscriptionPauseCollection {
+    pub behavior: Option<PauseCollectionBehavior>,
+    pub resumes_at: Option<Timestamp>,
+}
+
+impl Serialize for SubscriptionPauseCollection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.behavior.is_none() && self.resumes_at.is_none() {
+            return serializer.serialize_str("");
+        }
+
+        let mut state = serializer.serialize_struct("SubscriptionPauseCollection", 2)?;
+        if let Some(ref behavior) = self.behavior {
+            state.serialize_field("behavior", behavior)?;
+        }
+        if let Some(ref resumes_at) = self.resumes_at {
+            state.serialize_field("resumes_at", resumes_at)?;
+        }
+        state.end()
+    }
+}
+
+/// When a subscription's trial should end.
+///
+/// When updating a subscription, `Now` ends its trial immediately and a timestamp extends
+/// (or shortens) it.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/trials.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SubscriptionTrialEnd {
+    Now,
+    Timestamp(Timestamp),
+}
+
+impl Serialize for SubscriptionTrialEnd {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            SubscriptionTrialEnd::Now => serializer.serialize_str("now"),
+            SubscriptionTrialEnd::Timestamp(timestamp) => serializer.serialize_i64(timestamp),
+        }
+    }
+}
+
+#[deprecated(note = "renamed to SubscriptionTrialEnd")]
+pub type TrialEnd = SubscriptionTrialEnd;
+
+/// What happens to a subscription when its trial ends without a payment method.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/trials#create-free-trials-without-payment.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum MissingPaymentMethod {
+    Cancel,
+    CreateInvoice,
+    Pause,
+
+    /// A behavior not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for MissingPaymentMethod {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, MissingPaymentMethod::deserialize, MissingPaymentMethod::Other)
+    }
+}
+
+impl Serialize for MissingPaymentMethod {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            MissingPaymentMethod::Other(ref value) => serializer.serialize_str(value),
+            _ => MissingPaymentMethod::serialize(self, serializer),
+        }
+    }
+}
+
+/// What happens to a subscription when its trial ends.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TrialEndBehavior {
+    pub missing_payment_method: MissingPaymentMethod,
+}
+
+/// The trial settings of a subscription.
+///
+/// For more details see https://stripe.com/docs/api/subscriptions/object#subscription_object-trial_settings.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TrialSettings {
+    pub end_behavior: TrialEndBehavior,
+}
+
 /// The set of parameters that can be used when creating or updating a subscription.
 ///
+/// NOTE: `trial_from_plan` and `trial_period_days` can only be used when creating a subscription.
+///
 /// For more details see https://stripe.com/docs/api#create_subscription and https://stripe.com/docs/api#update_subscription.
 #[derive(Default, Serialize, Debug)]
 pub struct SubscriptionParams<'a> {
@@ -27,16 +185,22 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_cycle_anchor: Option<BillingCycleAnchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub coupon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<ItemParams<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_collection: Option<SubscriptionPauseCollection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub plan: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prorate: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_behavior: Option<ProrationBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proration_date: Option<Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantity: Option<u64>,
@@ -45,17 +209,138 @@ pub struct SubscriptionParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_percent: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trial_end: Option<TrialEnd<'a>>,
+    pub trial_end: Option<SubscriptionTrialEnd>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_from_plan: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_settings: Option<TrialSettings>,
 }
 
+/// The set of parameters that can be used when listing subscriptions.
+///
+/// For more details see https://stripe.com/docs/api#list_subscriptions
+#[derive(Default, Serialize)]
+pub struct SubscriptionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SubscriptionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<&'a str>,
+}
 
-#[derive(Serialize, Debug)]
-#[serde(untagged)]
-pub enum TrialEnd<'a> {
-    Timestamp(Timestamp),
-    Special(&'a str)
+impl<'a> ListParams for SubscriptionListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+
+/// The set of parameters that can be used when previewing the prorations of a subscription change.
+///
+/// For more details see https://stripe.com/docs/billing/subscriptions/prorations#preview-proration.
+#[derive(Debug, Serialize)]
+pub struct ProrationPreviewParams<'a> {
+    pub subscription: &'a str,
+    #[serde(rename = "subscription_items")]
+    pub items: Vec<SubscriptionItemParams<'a>>,
+    #[serde(rename = "subscription_proration_date")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+/// The prorations which a subscription change would invoice.
+#[derive(Debug)]
+pub struct ProrationPreview {
+    /// The upcoming invoice including the change.
+    pub invoice: Invoice,
+    /// The net amount of the prorations (credits are negative).
+    pub immediate_total: i64,
+    proration_date: Option<Timestamp>,
+}
+
+/// The most invoice lines `Subscription::preview_proration` will fetch.
+pub const MAX_PRORATION_LINES: usize = 10_000;
+
+impl ProrationPreview {
+    /// Computes the prorations of an upcoming invoice previewed with the given proration date.
+    ///
+    /// Fails if the invoice's `lines` has more pages, since prorations could be on any page
+    /// (`Subscription::preview_proration` fetches them before computing the preview).
+    pub fn from_invoice(invoice: Invoice, proration_date: Option<Timestamp>) -> Result<ProrationPreview, Error> {
+        if invoice.lines.has_more {
+            return Err(Error::Unsupported("can't compute prorations from an invoice with more pages of lines"));
+        }
+        let mut preview = ProrationPreview { invoice: invoice, immediate_total: 0, proration_date: proration_date };
+        preview.immediate_total = preview.proration_lines().iter().map(|line| line.amount).sum();
+        Ok(preview)
+    }
+
+    /// The invoice lines for the prorations of the change.
+    ///
+    /// If a proration date was given, only prorations from that date are included
+    /// (excluding eg. prorations from earlier changes which are also pending).
+    pub fn proration_lines(&self) -> Vec<&InvoiceLineItem> {
+        let proration_date = self.proration_date;
+        self.invoice.lines.data.iter()
+            .filter(|line| line.proration)
+            .filter(|line| proration_date.map_or(true, |date| line.period.start == date))
+            .collect()
+    }
+}
+
+/// The status of a subscription.
+///
+/// For more details see https://stripe.com/docs/api/subscriptions/object#subscription_object-status.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(remote = "Self", rename_all = "snake_case")]
+pub enum SubscriptionStatus {
+    Active,
+    Canceled,
+    Incomplete,
+    IncompleteExpired,
+    PastDue,
+    Paused,
+    Trialing,
+    Unpaid,
+
+    /// Matches subscriptions of any status (only valid when listing subscriptions)
+    All,
+
+    /// A status not yet known to this crate
+    #[serde(skip)]
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for SubscriptionStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_lenient(deserializer, SubscriptionStatus::deserialize, SubscriptionStatus::Other)
+    }
+}
+
+impl Serialize for SubscriptionStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            SubscriptionStatus::Other(ref value) => serializer.serialize_str(value),
+            _ => SubscriptionStatus::serialize(self, serializer),
+        }
+    }
 }
 
 /// The resource representing a Stripe subscription item.
@@ -66,6 +351,8 @@ pub struct SubscriptionItem {
     pub id: String,
     pub created: Timestamp,
     pub plan: Plan,
+    #[serde(default)]
+    pub price: Option<Price>,
     pub quantity: u64,
 }
 
@@ -76,6 +363,8 @@ pub struct SubscriptionItem {
 pub struct Subscription {
     pub id: String,
     pub application_fee_percent: Option<f64>,
+    #[serde(default)]
+    pub billing_cycle_anchor: Option<Timestamp>,
     pub cancel_at_period_end: bool,
     pub canceled_at: Option<Timestamp>,
     pub created: Option<Timestamp>,
@@ -87,13 +376,17 @@ pub struct Subscription {
     pub items: List<SubscriptionItem>,
     pub livemode: bool,
     pub metadata: Metadata,
+    #[serde(default)]
+    pub pause_collection: Option<SubscriptionPauseCollection>,
     pub plan: Plan,
     pub quantity: u64,
     pub start: Timestamp,
-    pub status: String, // (trialing, active, past_due, canceled, unpaid)
+    pub status: SubscriptionStatus,
     pub tax_percent: Option<f64>,
     pub trial_start: Option<Timestamp>,
     pub trial_end: Option<Timestamp>,
+    #[serde(default)]
+    pub trial_settings: Option<TrialSettings>,
 }
 
 impl Subscription {
@@ -108,19 +401,72 @@ impl Subscription {
     ///
     /// For more details see https://stripe.com/docs/api#retrieve_subscription.
     pub fn retrieve(client: &Client, subscription_id: &str) -> Result<Subscription, Error> {
-        client.get(&format!("/subscriptions/{}", subscription_id))
+        client.get_at(&["subscriptions", subscription_id])
     }
 
     /// Updates a subscription's properties.
     /// For more details see https://stripe.com/docs/api#update_subscription.
     pub fn update(client: &Client, subscription_id: &str, params: SubscriptionParams) -> Result<Subscription, Error> {
-        client.post(&format!("/subscriptions/{}", subscription_id), params)
+        client.post(&Client::path_for(&["subscriptions", subscription_id]), params)
+    }
+
+    /// Resumes payment collection on a subscription by clearing its `pause_collection`.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/pause#unpausing.
+    pub fn resume(client: &Client, subscription_id: &str) -> Result<Subscription, Error> {
+        let params = SubscriptionParams {
+            pause_collection: Some(SubscriptionPauseCollection::default()),
+            ..Default::default()
+        };
+        Subscription::update(client, subscription_id, params)
+    }
+
+    /// Previews the prorations of changing a subscription's items, without changing it.
+    ///
+    /// For more details see https://stripe.com/docs/billing/subscriptions/prorations#preview-proration.
+    ///
+    /// Fails with `Error::TooManyItems` if the upcoming invoice has more than `MAX_PRORATION_LINES` lines.
+    pub fn preview_proration(client: &Client, params: ProrationPreviewParams) -> Result<ProrationPreview, Error> {
+        let query = qs::to_string(&params)?;
+        let mut invoice: Invoice = client.get(&format!("/invoices/upcoming?{}", query))?;
+        if invoice.lines.has_more {
+            // NOTE: An upcoming invoice has no id, so its remaining lines are listed with the same params
+            let rest: Vec<InvoiceLineItem> = {
+                let cursor = invoice.lines.data.last().map(|line| line.id());
+                let max_items = MAX_PRORATION_LINES.saturating_sub(invoice.lines.data.len());
+                client.get_all("/invoices/upcoming/lines", &format!("{}&limit=100", query), cursor, max_items + 1)?
+            };
+            if invoice.lines.data.len() + rest.len() > MAX_PRORATION_LINES {
+                return Err(Error::TooManyItems { max_items: MAX_PRORATION_LINES });
+            }
+            invoice.lines.data.extend(rest);
+            invoice.lines.has_more = false;
+        }
+        ProrationPreview::from_invoice(invoice, params.proration_date)
     }
 
     /// Cancels a subscription.
     ///
     /// For more details see https://stripe.com/docs/api#cancel_subscription.
     pub fn cancel(client: &Client, subscription_id: &str, params: CancelParams) -> Result<Subscription, Error> {
-        client.delete(&format!("/subscriptions/{}?{}", subscription_id, qs::to_string(&params)?))
+        client.delete(&format!("{}?{}", Client::path_for(&["subscriptions", subscription_id]), qs::to_string(&params)?))
+    }
+
+    /// Lists subscriptions.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscriptions.
+    pub fn list(client: &Client, params: SubscriptionListParams) -> Result<List<Subscription>, Error> {
+        client.get(&format!("/subscriptions?{}", qs::to_string(&params)?))
+    }
+
+    /// Lists all subscriptions, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: SubscriptionListParams, max_items: usize) -> Result<Vec<Subscription>, Error> {
+        client.list_all("/subscriptions", params, max_items)
+    }
+}
+
+impl Object for Subscription {
+    fn id(&self) -> &str {
+        &self.id
     }
 }
@@ -0,0 +1,40 @@
+use error::Error;
+use client::Client;
+use params::{Metadata, Object, Timestamp};
+
+/// The resource representing a Stripe tax rate.
+///
+/// For more details see https://stripe.com/docs/api/tax_rates/object.
+#[derive(Debug, Deserialize)]
+pub struct TaxRate {
+    pub id: String,
+    pub object: String,
+    pub active: bool,
+    pub country: Option<String>,
+    pub created: Timestamp,
+    pub description: Option<String>,
+    pub display_name: String,
+    pub inclusive: bool,
+    pub jurisdiction: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub percentage: f64, // eg. 8.25 => 8.25%
+    pub state: Option<String>,
+    #[serde(default)]
+    pub tax_type: Option<String>, // (vat, sales_tax, gst, ..)
+}
+
+impl TaxRate {
+    /// Retrieves the details of a tax rate.
+    ///
+    /// For more details see https://stripe.com/docs/api/tax_rates/retrieve.
+    pub fn retrieve(client: &Client, tax_rate_id: &str) -> Result<TaxRate, Error> {
+        client.get_at(&["tax_rates", tax_rate_id])
+    }
+}
+
+impl Object for TaxRate {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,105 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams};
+use resources::Deleted;
+use serde_json as json;
+use serde_qs as qs;
+
+/// The set of parameters that can be used when creating or updating a terminal configuration.
+///
+/// For more details see https://stripe.com/docs/api/terminal/configuration/create.
+#[derive(Default, Serialize)]
+pub struct ConfigurationParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbpos_wisepos_e: Option<json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offline: Option<json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipping: Option<json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verifone_p400: Option<json::Value>,
+}
+
+/// The set of parameters that can be used when listing terminal configurations.
+///
+/// For more details see https://stripe.com/docs/api/terminal/configuration/list.
+#[derive(Default, Serialize)]
+pub struct ConfigurationListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_account_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for ConfigurationListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing the settings applied to a group of terminal readers.
+///
+/// For more details see https://stripe.com/docs/api/terminal/configuration/object.
+#[derive(Debug, Deserialize)]
+pub struct Configuration {
+    pub id: String,
+    pub object: String,
+    #[serde(default)]
+    pub bbpos_wisepos_e: Option<json::Value>,
+    pub is_account_default: Option<bool>,
+    pub livemode: bool,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub offline: Option<json::Value>,
+    #[serde(default)]
+    pub tipping: Option<json::Value>,
+    #[serde(default)]
+    pub verifone_p400: Option<json::Value>,
+}
+
+impl Configuration {
+    /// Creates a new terminal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/configuration/create.
+    pub fn create(client: &Client, params: ConfigurationParams) -> Result<Configuration, Error> {
+        client.post("/terminal/configurations", params)
+    }
+
+    /// Retrieves the details of a terminal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/configuration/retrieve.
+    pub fn retrieve(client: &Client, configuration_id: &str) -> Result<Configuration, Error> {
+        client.get_at(&["terminal", "configurations", configuration_id])
+    }
+
+    /// Updates a terminal configuration's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/configuration/update.
+    pub fn update(client: &Client, configuration_id: &str, params: ConfigurationParams) -> Result<Configuration, Error> {
+        client.post(&Client::path_for(&["terminal", "configurations", configuration_id]), params)
+    }
+
+    /// Deletes a terminal configuration.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/configuration/delete.
+    pub fn delete(client: &Client, configuration_id: &str) -> Result<Deleted, Error> {
+        client.delete(&Client::path_for(&["terminal", "configurations", configuration_id]))
+    }
+
+    /// Lists all terminal configurations.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/configuration/list.
+    pub fn list(client: &Client, params: ConfigurationListParams) -> Result<List<Configuration>, Error> {
+        client.get(&format!("/terminal/configurations?{}", qs::to_string(&params)?))
+    }
+}
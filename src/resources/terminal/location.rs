@@ -0,0 +1,94 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata};
+use resources::{Address, Deleted};
+use serde_qs as qs;
+
+/// The set of parameters that can be used when creating or updating a terminal location.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/create.
+#[derive(Default, Serialize)]
+pub struct LocationParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration_overrides: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing terminal locations.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/list.
+#[derive(Default, Serialize)]
+pub struct LocationListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for LocationListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a Stripe terminal location, which groups readers.
+///
+/// For more details see https://stripe.com/docs/api/terminal/locations/object.
+#[derive(Debug, Deserialize)]
+pub struct Location {
+    pub id: String,
+    pub object: String,
+    pub address: Address,
+    pub configuration_overrides: Option<String>,
+    pub display_name: String,
+    pub livemode: bool,
+    pub metadata: Metadata,
+}
+
+impl Location {
+    /// Creates a new terminal location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/create.
+    pub fn create(client: &Client, params: LocationParams) -> Result<Location, Error> {
+        client.post("/terminal/locations", params)
+    }
+
+    /// Retrieves the details of a terminal location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/retrieve.
+    pub fn retrieve(client: &Client, location_id: &str) -> Result<Location, Error> {
+        client.get_at(&["terminal", "locations", location_id])
+    }
+
+    /// Updates a terminal location's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/update.
+    pub fn update(client: &Client, location_id: &str, params: LocationParams) -> Result<Location, Error> {
+        client.post(&Client::path_for(&["terminal", "locations", location_id]), params)
+    }
+
+    /// Deletes a terminal location.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/delete.
+    pub fn delete(client: &Client, location_id: &str) -> Result<Deleted, Error> {
+        client.delete(&Client::path_for(&["terminal", "locations", location_id]))
+    }
+
+    /// Lists all terminal locations.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/locations/list.
+    pub fn list(client: &Client, params: LocationListParams) -> Result<List<Location>, Error> {
+        client.get(&format!("/terminal/locations?{}", qs::to_string(&params)?))
+    }
+}
@@ -0,0 +1,11 @@
+//! Resources for Stripe Terminal, which accepts in-person payments with card readers.
+//!
+//! For more details see https://stripe.com/docs/terminal.
+
+mod configuration;
+mod location;
+mod reader;
+
+pub use resources::terminal::configuration::*;
+pub use resources::terminal::location::*;
+pub use resources::terminal::reader::*;
@@ -0,0 +1,260 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata};
+use resources::{Currency, Deleted};
+use serde_json as json;
+use serde_qs as qs;
+
+/// The network status of a terminal reader.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReaderStatus {
+    Offline,
+    Online,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when creating or updating a terminal reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/create.
+#[derive(Default, Serialize)]
+pub struct ReaderParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_code: Option<&'a str>, // required when creating a reader
+}
+
+/// The set of parameters that can be used when listing terminal readers.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/list.
+#[derive(Default, Serialize)]
+pub struct ReaderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<&'a str>, // (bbpos_wisepos_e, stripe_m2, verifone_P400, ..)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>, // (offline, online)
+}
+
+impl<'a> ListParams for ReaderListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// Options controlling how a reader processes a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/process_payment_intent.
+#[derive(Default, Serialize)]
+pub struct ReaderProcessConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_customer_cancellation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_tipping: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct ProcessPaymentIntentParams<'a> {
+    payment_intent: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    process_config: Option<ReaderProcessConfig>,
+}
+
+#[derive(Serialize)]
+struct ProcessSetupIntentParams<'a> {
+    setup_intent: &'a str,
+    customer_consent_collected: bool,
+}
+
+#[derive(Serialize)]
+struct PaymentIntentActionParams<'a> {
+    payment_intent: &'a str,
+}
+
+/// A line item shown on a reader's cart display.
+#[derive(Serialize)]
+pub struct ReaderDisplayLineItem<'a> {
+    pub amount: i64,
+    pub description: &'a str,
+    pub quantity: u64,
+}
+
+/// The cart shown on a reader's display.
+#[derive(Serialize)]
+pub struct ReaderDisplayCart<'a> {
+    pub currency: Currency,
+    pub line_items: Vec<ReaderDisplayLineItem<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<i64>,
+    pub total: i64,
+}
+
+/// The set of parameters that can be used when setting a reader's display.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/set_reader_display.
+#[derive(Serialize)]
+pub struct ReaderDisplayParams<'a> {
+    #[serde(rename = "type")]
+    pub display_type: &'a str, // (cart)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cart: Option<ReaderDisplayCart<'a>>,
+}
+
+/// The text shown to the customer when collecting an input.
+#[derive(Serialize)]
+pub struct ReaderInputCustomText<'a> {
+    pub title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_button: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_button: Option<&'a str>,
+}
+
+/// An input to collect from the customer on a reader.
+#[derive(Serialize)]
+pub struct ReaderInput<'a> {
+    #[serde(rename = "type")]
+    pub input_type: &'a str, // (email, numeric, phone, selection, signature, text)
+    pub custom_text: ReaderInputCustomText<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+}
+
+/// The set of parameters that can be used when collecting inputs on a reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/collect_inputs.
+#[derive(Serialize)]
+pub struct ReaderCollectInputsParams<'a> {
+    pub inputs: Vec<ReaderInput<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The resource representing a Stripe terminal reader.
+///
+/// For more details see https://stripe.com/docs/api/terminal/readers/object.
+#[derive(Debug, Deserialize)]
+pub struct Reader {
+    pub id: String,
+    pub object: String,
+    #[serde(default)]
+    pub action: Option<json::Value>,
+    pub device_sw_version: Option<String>,
+    pub device_type: String, // (bbpos_wisepos_e, stripe_m2, verifone_P400, ..)
+    pub ip_address: Option<String>,
+    pub label: String,
+    pub livemode: bool,
+    pub location: Option<String>,
+    pub metadata: Metadata,
+    pub serial_number: String,
+    pub status: Option<ReaderStatus>,
+}
+
+impl Reader {
+    /// Registers a new terminal reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/create.
+    pub fn create(client: &Client, params: ReaderParams) -> Result<Reader, Error> {
+        client.post("/terminal/readers", params)
+    }
+
+    /// Retrieves the details of a terminal reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/retrieve.
+    pub fn retrieve(client: &Client, reader_id: &str) -> Result<Reader, Error> {
+        client.get_at(&["terminal", "readers", reader_id])
+    }
+
+    /// Updates a terminal reader's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/update.
+    pub fn update(client: &Client, reader_id: &str, params: ReaderParams) -> Result<Reader, Error> {
+        client.post(&Client::path_for(&["terminal", "readers", reader_id]), params)
+    }
+
+    /// Deletes a terminal reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/delete.
+    pub fn delete(client: &Client, reader_id: &str) -> Result<Deleted, Error> {
+        client.delete(&Client::path_for(&["terminal", "readers", reader_id]))
+    }
+
+    /// Lists all terminal readers.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/list.
+    pub fn list(client: &Client, params: ReaderListParams) -> Result<List<Reader>, Error> {
+        client.get(&format!("/terminal/readers?{}", qs::to_string(&params)?))
+    }
+
+    /// Hands a payment intent to the reader for processing.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/process_payment_intent.
+    pub fn process_payment_intent(client: &Client, reader_id: &str, payment_intent_id: &str, config: Option<ReaderProcessConfig>) -> Result<Reader, Error> {
+        let params = ProcessPaymentIntentParams { payment_intent: payment_intent_id, process_config: config };
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "process_payment_intent"]), params)
+    }
+
+    /// Hands a setup intent to the reader for processing.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/process_setup_intent.
+    pub fn process_setup_intent(client: &Client, reader_id: &str, setup_intent_id: &str, customer_consent_collected: bool) -> Result<Reader, Error> {
+        let params = ProcessSetupIntentParams { setup_intent: setup_intent_id, customer_consent_collected: customer_consent_collected };
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "process_setup_intent"]), params)
+    }
+
+    /// Sets the reader display to show cart details.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/set_reader_display.
+    pub fn set_reader_display(client: &Client, reader_id: &str, params: ReaderDisplayParams) -> Result<Reader, Error> {
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "set_reader_display"]), params)
+    }
+
+    /// Cancels the reader's current action.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/cancel_action.
+    pub fn cancel_action(client: &Client, reader_id: &str) -> Result<Reader, Error> {
+        client.post_empty(&Client::path_for(&["terminal", "readers", reader_id, "cancel_action"]))
+    }
+
+    /// Collects a payment method for a payment intent without confirming it.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/collect_payment_method.
+    pub fn collect_payment_method(client: &Client, reader_id: &str, payment_intent_id: &str) -> Result<Reader, Error> {
+        let params = PaymentIntentActionParams { payment_intent: payment_intent_id };
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "collect_payment_method"]), params)
+    }
+
+    /// Confirms a payment intent whose payment method was collected on the reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/confirm_payment_intent.
+    pub fn confirm_payment_intent(client: &Client, reader_id: &str, payment_intent_id: &str) -> Result<Reader, Error> {
+        let params = PaymentIntentActionParams { payment_intent: payment_intent_id };
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "confirm_payment_intent"]), params)
+    }
+
+    /// Collects inputs, such as an email or signature, from the customer on the reader.
+    ///
+    /// For more details see https://stripe.com/docs/api/terminal/readers/collect_inputs.
+    pub fn collect_inputs(client: &Client, reader_id: &str, params: ReaderCollectInputsParams) -> Result<Reader, Error> {
+        client.post(&Client::path_for(&["terminal", "readers", reader_id, "collect_inputs"]), params)
+    }
+}
@@ -0,0 +1,209 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Timestamp};
+use resources::Deleted;
+use serde_qs as qs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The status of a test clock.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestClockStatus {
+    Advancing,
+    InternalFailure,
+    Ready,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when creating a test clock.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/create.
+#[derive(Default, Serialize)]
+pub struct TestClockParams<'a> {
+    pub frozen_time: Timestamp, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when advancing a test clock.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/advance.
+#[derive(Default, Serialize)]
+pub struct TestClockAdvanceParams {
+    pub frozen_time: Timestamp,
+}
+
+/// The set of parameters that can be used when listing test clocks.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/list.
+#[derive(Default, Serialize)]
+pub struct TestClockListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for TestClockListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a Stripe test clock.
+///
+/// For more details see https://stripe.com/docs/api/test_clocks/object.
+#[derive(Debug, Deserialize)]
+pub struct TestClock {
+    pub id: String,
+    pub object: String,
+    pub created: Timestamp,
+    pub deletes_after: Timestamp,
+    pub frozen_time: Timestamp,
+    pub livemode: bool,
+    pub name: Option<String>,
+    pub status: TestClockStatus,
+}
+
+impl TestClock {
+    /// Creates a new test clock.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/create.
+    pub fn create(client: &Client, params: TestClockParams) -> Result<TestClock, Error> {
+        ensure_test_mode(client)?;
+        client.post("/test_helpers/test_clocks", params)
+    }
+
+    /// Retrieves the details of a test clock.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/retrieve.
+    pub fn retrieve(client: &Client, test_clock_id: &str) -> Result<TestClock, Error> {
+        ensure_test_mode(client)?;
+        client.get_at(&["test_helpers", "test_clocks", test_clock_id])
+    }
+
+    /// Deletes a test clock.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/delete.
+    pub fn delete(client: &Client, test_clock_id: &str) -> Result<Deleted, Error> {
+        ensure_test_mode(client)?;
+        client.delete(&Client::path_for(&["test_helpers", "test_clocks", test_clock_id]))
+    }
+
+    /// Lists all test clocks.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/list.
+    pub fn list(client: &Client, params: TestClockListParams) -> Result<List<TestClock>, Error> {
+        ensure_test_mode(client)?;
+        client.get(&format!("/test_helpers/test_clocks?{}", qs::to_string(&params)?))
+    }
+
+    /// Starts advancing a test clock to the given time.
+    ///
+    /// Advancing happens asynchronously; the returned clock will usually be `Advancing`.
+    /// See `TestClock::wait_until_ready` to wait for it to finish.
+    ///
+    /// For more details see https://stripe.com/docs/api/test_clocks/advance.
+    pub fn advance(client: &Client, test_clock_id: &str, params: TestClockAdvanceParams) -> Result<TestClock, Error> {
+        ensure_test_mode(client)?;
+        client.post(&Client::path_for(&["test_helpers", "test_clocks", test_clock_id, "advance"]), params)
+    }
+
+    /// Polls a test clock every `poll` until it is no longer advancing.
+    ///
+    /// Returns the clock once it is no longer advancing, `Error::InvalidOperation` if it
+    /// failed to advance (ie. its status is `InternalFailure`), or `Error::Timeout` if it
+    /// is still advancing after `timeout`.
+    pub fn wait_until_ready(client: &Client, test_clock_id: &str, poll: Duration, timeout: Duration) -> Result<TestClock, Error> {
+        let started = Instant::now();
+        loop {
+            let clock = TestClock::retrieve(client, test_clock_id)?;
+            match clock.status {
+                TestClockStatus::Advancing => {}
+                TestClockStatus::InternalFailure => return Err(Error::InvalidOperation("test clock failed to advance")),
+                _ => return Ok(clock),
+            }
+            if started.elapsed() + poll > timeout {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(poll);
+        }
+    }
+}
+
+fn ensure_test_mode(client: &Client) -> Result<(), Error> {
+    if client.is_test_mode() {
+        Ok(())
+    } else {
+        Err(Error::Unsupported("test clocks require a test mode secret key"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn live_key() {
+        use super::{TestClock, TestClockParams};
+        use client::Client;
+        use error::Error;
+        use mock::UNREACHABLE;
+
+        let client = Client::new("sk_live_123").with_api_base(UNREACHABLE);
+        match TestClock::create(&client, TestClockParams::default()) {
+            Err(Error::Unsupported(msg)) => assert_eq!(msg, "test clocks require a test mode secret key"),
+            other => panic!("expected an unsupported error, got {:?}", other),
+        }
+        assert!(TestClock::retrieve(&client, "clock_123").is_err());
+        assert_eq!(client.request_count(), 0);
+    }
+
+    #[test]
+    fn wait_until_ready() {
+        use super::{TestClock, TestClockStatus};
+        use client::Client;
+        use error::Error;
+        use mock::{ok, serve};
+        use std::time::Duration;
+
+        let clock = |status: &str| format!(
+            r#"{{"id":"clock_123","object":"test_helpers.test_clock","created":1500000000,"deletes_after":1500600000,"frozen_time":1500000000,"livemode":false,"name":null,"status":"{}"}}"#,
+            status
+        );
+        let api_base = |statuses: Vec<&str>| serve(ok(statuses.into_iter().map(|status| clock(status)).collect())).0;
+        let poll = Duration::from_millis(1);
+        let timeout = Duration::from_secs(5);
+
+        // Waiting stops once the clock is ready
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["advancing", "advancing", "ready"]));
+        let ready = TestClock::wait_until_ready(&client, "clock_123", poll, timeout).unwrap();
+        assert_eq!(ready.status, TestClockStatus::Ready);
+        assert_eq!(client.request_count(), 3);
+
+        // ... or at a status not yet known to this crate
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["advancing", "paused"]));
+        let paused = TestClock::wait_until_ready(&client, "clock_123", poll, timeout).unwrap();
+        assert_eq!(paused.status, TestClockStatus::Other);
+
+        // ... but fails if the clock couldn't advance
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["advancing", "internal_failure"]));
+        match TestClock::wait_until_ready(&client, "clock_123", poll, timeout) {
+            Err(Error::InvalidOperation(msg)) => assert_eq!(msg, "test clock failed to advance"),
+            other => panic!("expected an invalid operation error, got {:?}", other),
+        }
+
+        // ... or times out while it is still advancing
+        let client = Client::new("sk_test_123").with_api_base(api_base(vec!["advancing"]));
+        match TestClock::wait_until_ready(&client, "clock_123", poll, Duration::from_millis(0)) {
+            Err(Error::Timeout) => assert_eq!(client.request_count(), 1),
+            other => panic!("expected a timeout, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata, Object, RangeQuery, Timestamp};
+use resources::{Currency, Source};
+use serde_qs as qs;
+
+/// The status of a top-up.
+///
+/// For more details see https://stripe.com/docs/api/topups/object#topup_object-status.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopupStatus {
+    Canceled,
+    Failed,
+    Pending,
+    Reversed,
+    Succeeded,
+    #[serde(other)]
+    Other,
+}
+
+/// The set of parameters that can be used when creating a top-up.
+///
+/// For more details see https://stripe.com/docs/api/topups/create.
+#[derive(Serialize)]
+pub struct TopupCreateParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>, // NOTE: if None, the platform's default bank account is used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a top-up.
+///
+/// For more details see https://stripe.com/docs/api/topups/update.
+#[derive(Default, Serialize)]
+pub struct TopupUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when canceling a top-up.
+///
+/// For more details see https://stripe.com/docs/api/topups/cancel.
+#[derive(Default, Serialize)]
+pub struct TopupCancelParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<Vec<&'a str>>,
+}
+
+/// The set of parameters that can be used when listing top-ups.
+///
+/// For more details see https://stripe.com/docs/api/topups/list.
+#[derive(Default, Serialize)]
+pub struct TopupListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<RangeQuery<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'a str>, // (canceled, failed, pending, succeeded)
+}
+
+impl<'a> ListParams for TopupListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing a Stripe top-up, which adds funds to a Stripe balance from a bank account.
+///
+/// For more details see https://stripe.com/docs/api/topups/object.
+#[derive(Debug, Deserialize)]
+pub struct Topup {
+    pub id: String,
+    pub object: String,
+    pub amount: u64,
+    pub balance_transaction: Option<String>,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub description: Option<String>,
+    pub expected_availability_date: Option<Timestamp>,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    pub source: Option<Source>,
+    pub statement_descriptor: Option<String>,
+    pub status: TopupStatus,
+    pub transfer_group: Option<String>,
+}
+
+impl Topup {
+    /// Creates a new top-up.
+    ///
+    /// For more details see https://stripe.com/docs/api/topups/create.
+    pub fn create(client: &Client, params: TopupCreateParams) -> Result<Topup, Error> {
+        client.post("/topups", params)
+    }
+
+    /// Retrieves the details of a top-up.
+    ///
+    /// For more details see https://stripe.com/docs/api/topups/retrieve.
+    pub fn retrieve(client: &Client, topup_id: &str) -> Result<Topup, Error> {
+        client.get_at(&["topups", topup_id])
+    }
+
+    /// Updates a top-up's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/topups/update.
+    pub fn update(client: &Client, topup_id: &str, params: TopupUpdateParams) -> Result<Topup, Error> {
+        client.post(&Client::path_for(&["topups", topup_id]), params)
+    }
+
+    /// Cancels a top-up which is still pending.
+    ///
+    /// For more details see https://stripe.com/docs/api/topups/cancel.
+    pub fn cancel(client: &Client, topup_id: &str, params: TopupCancelParams) -> Result<Topup, Error> {
+        client.post(&Client::path_for(&["topups", topup_id, "cancel"]), params)
+    }
+
+    /// Lists all top-ups.
+    ///
+    /// For more details see https://stripe.com/docs/api/topups/list.
+    pub fn list(client: &Client, params: TopupListParams) -> Result<List<Topup>, Error> {
+        client.get(&format!("/topups?{}", qs::to_string(&params)?))
+    }
+
+    /// Lists all top-ups, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: TopupListParams, max_items: usize) -> Result<Vec<Topup>, Error> {
+        client.list_all("/topups", params, max_items)
+    }
+}
+
+impl Object for Topup {
+    fn id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,43 @@
+use resources::{Discount, TaxRate};
+
+/// The amount discounted by one of the discounts applied to an invoice or checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object#checkout_session_object-total_details-breakdown-discounts.
+#[derive(Debug, Deserialize)]
+pub struct DiscountAmount {
+    pub amount: i64,
+    pub discount: Discount,
+}
+
+/// The amount of tax charged at one of the tax rates applied to an invoice or checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object#checkout_session_object-total_details-breakdown-taxes.
+#[derive(Debug, Deserialize)]
+pub struct TaxAmount {
+    pub amount: i64,
+    pub rate: TaxRate,
+    #[serde(default)]
+    pub taxability_reason: Option<String>,
+    #[serde(default)]
+    pub taxable_amount: Option<i64>,
+}
+
+/// The discounts and taxes which make up the totals of an invoice or checkout session.
+#[derive(Debug, Deserialize)]
+pub struct TotalDetailsBreakdown {
+    pub discounts: Vec<DiscountAmount>,
+    pub taxes: Vec<TaxAmount>,
+}
+
+/// The amounts discounted, taxed and charged for shipping in an invoice or checkout session.
+///
+/// For more details see https://stripe.com/docs/api/checkout/sessions/object#checkout_session_object-total_details.
+#[derive(Debug, Deserialize)]
+pub struct TotalDetails {
+    pub amount_discount: i64,
+    #[serde(default)]
+    pub amount_shipping: Option<i64>,
+    pub amount_tax: i64,
+    #[serde(default)]
+    pub breakdown: Option<TotalDetailsBreakdown>, // only present when expanded
+}
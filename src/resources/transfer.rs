@@ -1,5 +1,69 @@
-use params::{List, Metadata, Timestamp};
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Metadata, Object, RangeQuery, Timestamp};
 use resources::Currency;
+use serde_qs as qs;
+
+/// The set of parameters that can be used when creating a transfer.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer.
+#[derive(Serialize)]
+pub struct TransferParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    pub destination: &'a str, // the id of a connected account
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<&'a str>, // the id of a charge to transfer funds from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when reversing a transfer.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+#[derive(Default, Serialize)]
+pub struct TransferReversalParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>, // NOTE: if None, the whole transfer is reversed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing transfers.
+///
+/// For more details see https://stripe.com/docs/api#list_transfers.
+#[derive(Default, Serialize)]
+pub struct TransferListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+impl<'a> ListParams for TransferListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
 
 /// The resource representing a Stripe transfer reversal.
 ///
@@ -35,7 +99,48 @@ pub struct Transfer {
     pub metadata: Metadata,
     pub reversals: List<TransferReversal>,
     pub reversed: bool,
-    pub source_transaction: String,
+    pub source_transaction: Option<String>,
     pub source_type: String,
-    pub transfer_group: String,
+    pub transfer_group: Option<String>,
+}
+
+impl Transfer {
+    /// Creates a new transfer to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer.
+    pub fn create(client: &Client, params: TransferParams) -> Result<Transfer, Error> {
+        client.post("/transfers", params)
+    }
+
+    /// Retrieves the details of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_transfer.
+    pub fn retrieve(client: &Client, transfer_id: &str) -> Result<Transfer, Error> {
+        client.get_at(&["transfers", transfer_id])
+    }
+
+    /// Reverses a transfer, returning the funds to the platform's balance.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+    pub fn reverse(client: &Client, transfer_id: &str, params: TransferReversalParams) -> Result<TransferReversal, Error> {
+        client.post(&Client::path_for(&["transfers", transfer_id, "reversals"]), params)
+    }
+
+    /// Lists all transfers.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_transfers.
+    pub fn list(client: &Client, params: TransferListParams) -> Result<List<Transfer>, Error> {
+        client.get(&format!("/transfers?{}", qs::to_string(&params)?))
+    }
+
+    /// Lists all transfers, following pagination, failing if there are more than `max_items`.
+    pub fn list_all(client: &Client, params: TransferListParams, max_items: usize) -> Result<Vec<Transfer>, Error> {
+        client.list_all("/transfers", params, max_items)
+    }
+}
+
+impl Object for Transfer {
+    fn id(&self) -> &str {
+        &self.id
+    }
 }
@@ -0,0 +1,25 @@
+/// Where the funds of a destination charge (or payment intent) are transferred.
+///
+/// For more details see https://stripe.com/docs/connect/destination-charges.
+#[derive(Serialize)]
+pub struct TransferDataParams<'a> {
+    pub destination: &'a str, // the id of a connected account
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>, // NOTE: if None, the full amount (less any application fee) is transferred
+}
+
+/// Where the funds of a destination charge (or payment intent) were transferred.
+#[derive(Debug, Deserialize)]
+pub struct TransferData {
+    pub amount: Option<i64>, // (absent if the full amount was transferred)
+    pub destination: String,
+}
+
+/// The transfer data which can be changed after a destination charge (or payment intent) is created,
+/// eg. when capturing it.
+#[derive(Default, Serialize)]
+pub struct TransferDataUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+}
@@ -0,0 +1,63 @@
+use error::Error;
+use client::Client;
+use params::{List, ListParams, Timestamp};
+use serde_qs as qs;
+
+/// The billing period covered by a usage record summary.
+///
+/// The start is `None` for summaries of usage reported before the first period.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummaryPeriod {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+
+/// The set of parameters that can be used when listing usage record summaries.
+///
+/// For more details see https://stripe.com/docs/api/usage_records/subscription_item_summary_list.
+#[derive(Default, Serialize)]
+pub struct UsageRecordSummaryListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl<'a> ListParams for UsageRecordSummaryListParams<'a> {
+    fn take_starting_after(&mut self) -> Option<String> {
+        self.starting_after.take().map(|id| id.to_string())
+    }
+
+    fn default_limit(&mut self, limit: u64) {
+        self.limit = self.limit.or(Some(limit));
+    }
+}
+
+/// The resource representing the usage reported for a metered subscription item over a billing period.
+///
+/// For more details see https://stripe.com/docs/api/usage_records/subscription_item_summary_list.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummary {
+    pub id: String,
+    pub object: String,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub period: UsageRecordSummaryPeriod,
+    pub subscription_item: String,
+    pub total_usage: i64,
+}
+
+impl UsageRecordSummary {
+    /// Lists the usage record summaries for a subscription item, one per billing period.
+    ///
+    /// For more details see https://stripe.com/docs/api/usage_records/subscription_item_summary_list.
+    pub fn list(client: &Client, subscription_item_id: &str, params: UsageRecordSummaryListParams) -> Result<List<UsageRecordSummary>, Error> {
+        client.get(&format!(
+            "/subscription_items/{}/usage_record_summaries?{}",
+            subscription_item_id,
+            qs::to_string(&params)?
+        ))
+    }
+}
@@ -0,0 +1,148 @@
+//! Helpers for testing code which handles Stripe webhooks.
+//!
+//! These are only available with the `test-helpers` feature.
+
+use chrono::Utc;
+use client::DEFAULT_API_VERSION;
+use resources::Webhook;
+use serde_json as json;
+
+#[derive(Serialize)]
+struct MockEvent<'a> {
+    id: String,
+    object: &'static str,
+    api_version: &'static str,
+    created: i64,
+    data: MockEventData,
+    livemode: bool,
+    pending_webhooks: u64,
+    request: MockEventRequest,
+    #[serde(rename = "type")]
+    event_type: &'a str,
+}
+
+#[derive(Serialize)]
+struct MockEventData {
+    object: json::Value,
+}
+
+#[derive(Serialize)]
+struct MockEventRequest {
+    id: Option<String>,
+    idempotency_key: Option<String>,
+}
+
+/// Wraps `object` in an event of type `event_type`, returning its payload and the
+/// `Stripe-Signature` header signing it with `secret`.
+///
+/// The two can be passed straight to `Webhook::construct_event`.
+pub fn mock_event(event_type: &str, object: json::Value, secret: &str) -> (String, String) {
+    let created = Utc::now().timestamp();
+    let event = MockEvent {
+        id: format!("evt_test_{}", created),
+        object: "event",
+        api_version: DEFAULT_API_VERSION,
+        created: created,
+        data: MockEventData { object: object },
+        livemode: false,
+        pending_webhooks: 1,
+        request: MockEventRequest { id: None, idempotency_key: None },
+        event_type: event_type,
+    };
+    let payload = json::to_string(&event).expect("mock events are always serializable");
+    let header = Webhook::signature_header(&payload, created, secret);
+    (payload, header)
+}
+
+/// Returns a canned object for some of the most common event types, or `None` for other types.
+///
+/// Supports `invoice.payment_succeeded`, `customer.subscription.deleted` and `charge.refunded`.
+pub fn fixture(event_type: &str) -> Option<json::Value> {
+    let object = match event_type {
+        "invoice.payment_succeeded" => INVOICE_PAID,
+        "customer.subscription.deleted" => SUBSCRIPTION_CANCELED,
+        "charge.refunded" => CHARGE_REFUNDED,
+        _ => return None,
+    };
+    Some(json::from_str(object).expect("fixtures are valid json"))
+}
+
+const INVOICE_PAID: &'static str = r#"{
+    "id": "in_test_123",
+    "object": "invoice",
+    "amount_due": 2000,
+    "attempt_count": 1,
+    "attempted": true,
+    "charge": "ch_test_123",
+    "closed": true,
+    "collection_method": "charge_automatically",
+    "currency": "usd",
+    "customer": "cus_test_123",
+    "date": 1500000000,
+    "ending_balance": 0,
+    "forgiven": false,
+    "lines": {"object": "list", "data": [], "has_more": false, "url": "/v1/invoices/in_test_123/lines"},
+    "livemode": false,
+    "metadata": {},
+    "paid": true,
+    "period_end": 1500000000,
+    "period_start": 1497408000,
+    "starting_balance": 0,
+    "subscription": "sub_test_123",
+    "subtotal": 2000,
+    "total": 2000
+}"#;
+
+const SUBSCRIPTION_CANCELED: &'static str = r#"{
+    "id": "sub_test_123",
+    "object": "subscription",
+    "application_fee_percent": null,
+    "cancel_at_period_end": false,
+    "canceled_at": 1500000000,
+    "created": 1497408000,
+    "current_period_start": 1497408000,
+    "current_period_end": 1500000000,
+    "customer": "cus_test_123",
+    "discount": null,
+    "ended_at": 1500000000,
+    "items": {"object": "list", "data": [], "has_more": false, "url": "/v1/subscription_items?subscription=sub_test_123"},
+    "livemode": false,
+    "metadata": {},
+    "plan": {
+        "id": "plan_test_123",
+        "amount": 2000,
+        "created": 1497408000,
+        "currency": "usd",
+        "interval": "month",
+        "interval_count": 1,
+        "livemode": false,
+        "metadata": {},
+        "nickname": "Basic",
+        "statement_descriptor": null,
+        "trial_period_days": null
+    },
+    "quantity": 1,
+    "start": 1497408000,
+    "status": "canceled",
+    "tax_percent": null,
+    "trial_start": null,
+    "trial_end": null
+}"#;
+
+const CHARGE_REFUNDED: &'static str = r#"{
+    "id": "ch_test_123",
+    "object": "charge",
+    "amount": 2000,
+    "amount_refunded": 2000,
+    "captured": true,
+    "created": 1500000000,
+    "currency": "usd",
+    "fraud_details": {},
+    "livemode": false,
+    "metadata": {},
+    "paid": true,
+    "refunded": true,
+    "refunds": {"object": "list", "data": [], "has_more": false, "url": "/v1/charges/ch_test_123/refunds"},
+    "source": {"object": "bitcoin_receiver"},
+    "status": "succeeded"
+}"#;
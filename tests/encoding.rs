@@ -63,6 +63,1804 @@ fn serialize_range_query() {
     assert_eq!(encoded, "created[gte]=1501598702&created[lt]=1504233902&limit=3");
 }
 
+#[test]
+fn serialize_invoice_item_params() {
+    use stripe::{Currency, InvoiceItemCreateParams, InvoiceItemPriceData, Period};
+
+    let mut params = InvoiceItemCreateParams::default();
+    params.customer = "cus_123";
+    params.period = Some(Period { start: 1501598702, end: 1504233902 });
+    params.price_data = Some(InvoiceItemPriceData {
+        currency: Currency::USD,
+        product: "prod_123",
+        unit_amount: None,
+        unit_amount_decimal: Some("0.5"),
+    });
+    let encoded = urldecode(qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "customer=cus_123&period[start]=1501598702&period[end]=1504233902\
+         &price_data[currency]=usd&price_data[product]=prod_123&price_data[unit_amount_decimal]=0.5"
+    );
+}
+
+#[test]
+fn deserialize_charge_outcome() {
+    use stripe::{ChargeOutcome, NetworkStatus, OutcomeType, RiskLevel};
+
+    let outcome: ChargeOutcome = json::from_str(
+        r#"{
+            "network_status": "approved_by_network",
+            "reason": null,
+            "risk_level": "elevated",
+            "risk_score": 67,
+            "seller_message": "Payment complete.",
+            "type": "authorized"
+        }"#,
+    ).unwrap();
+    assert_eq!(outcome.network_status, Some(NetworkStatus::ApprovedByNetwork));
+    assert_eq!(outcome.risk_level, Some(RiskLevel::Elevated));
+    assert_eq!(outcome.risk_score, Some(67));
+    assert_eq!(outcome.outcome_type, OutcomeType::Authorized);
+}
+
+#[test]
+fn serialize_customer_invoice_settings() {
+    use stripe::{CustomerParams, InvoiceCustomField, InvoiceSettings};
+
+    let mut params = CustomerParams::default();
+    params.invoice_settings = Some(InvoiceSettings {
+        default_payment_method: Some("pm_x".to_string()),
+        ..InvoiceSettings::default()
+    });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "invoice_settings[default_payment_method]=pm_x");
+
+    let mut params = CustomerParams::default();
+    params.invoice_settings = Some(InvoiceSettings {
+        custom_fields: Some(vec![InvoiceCustomField { name: "VAT".to_string(), value: "123".to_string() }]),
+        footer: Some("Thanks".to_string()),
+        ..InvoiceSettings::default()
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "invoice_settings[custom_fields][0][name]=VAT&invoice_settings[custom_fields][0][value]=123\
+         &invoice_settings[footer]=Thanks"
+    );
+}
+
+#[test]
+fn deserialize_last_payment_error() {
+    use stripe::{DeclineCode, ErrorCode, ErrorType, Expandable, PaymentIntentLastPaymentError};
+
+    let err: PaymentIntentLastPaymentError = json::from_str(
+        r#"{
+            "charge": "ch_123",
+            "code": "card_declined",
+            "decline_code": "insufficient_funds",
+            "message": "Your card has insufficient funds.",
+            "payment_method": "pm_123",
+            "type": "card_error"
+        }"#,
+    ).unwrap();
+    assert_eq!(err.error_type, ErrorType::Card);
+    assert_eq!(err.code, Some(ErrorCode::CardDeclined));
+    assert_eq!(err.decline_code, Some(DeclineCode::InsufficientFunds));
+    match err.payment_method {
+        Some(Expandable::Id(ref id)) => assert_eq!(id, "pm_123"),
+        _ => panic!("expected an unexpanded payment method"),
+    }
+}
+
+#[test]
+fn serialize_shipping() {
+    use stripe::{Address, ChargeParams, CustomerParams, PaymentIntentParams, Shipping};
+
+    let shipping = || Shipping {
+        address: Some(Address {
+            line1: Some("1 Main St".to_string()),
+            city: Some("Springfield".to_string()),
+            country: Some("US".to_string()),
+            ..Address::default()
+        }),
+        name: Some("Jane Doe".to_string()),
+        ..Shipping::default()
+    };
+    let expected = "shipping[address][line1]=1+Main+St&shipping[address][city]=Springfield\
+                    &shipping[address][country]=US&shipping[name]=Jane+Doe";
+
+    let mut params = CustomerParams::default();
+    params.shipping = Some(shipping());
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), expected);
+
+    let mut params = ChargeParams::default();
+    params.shipping = Some(shipping());
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), expected);
+
+    let mut params = PaymentIntentParams::default();
+    params.shipping = Some(shipping());
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), expected);
+
+    let shipping: Shipping = json::from_str(
+        r#"{
+            "address": {
+                "city": "Springfield", "country": "US", "line1": "1 Main St", "line2": null,
+                "postal_code": "12345", "state": "IL"
+            },
+            "carrier": "USPS", "name": "Jane Doe", "phone": null, "tracking_number": "9400100000000000000000"
+        }"#,
+    ).unwrap();
+    assert_eq!(shipping.address.unwrap().postal_code, Some("12345".to_string()));
+    assert_eq!(shipping.carrier, Some("USPS".to_string()));
+    assert_eq!(shipping.tracking_number, Some("9400100000000000000000".to_string()));
+}
+
+#[test]
+fn serialize_payment_intent_capture_params() {
+    use stripe::{PaymentIntentCaptureParams, TransferDataUpdateParams};
+
+    let mut params = PaymentIntentCaptureParams::default();
+    params.amount_to_capture = Some(750);
+    params.statement_descriptor_suffix = Some("ORDER 42".to_string());
+    params.transfer_data = Some(TransferDataUpdateParams { amount: Some(500) });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount_to_capture=750&statement_descriptor_suffix=ORDER+42&transfer_data[amount]=500"
+    );
+}
+
+#[test]
+fn serialize_payment_intent_update_params() {
+    use stripe::{PaymentIntentUpdateParams, TransferDataUpdateParams};
+
+    let mut params = PaymentIntentUpdateParams::default();
+    params.amount = Some(1200);
+    params.transfer_data = Some(TransferDataUpdateParams { amount: Some(900) });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amount=1200&transfer_data[amount]=900");
+}
+
+#[test]
+fn deserialize_next_action() {
+    use stripe::{NextAction, NextActionType};
+
+    let action: NextAction = json::from_str(
+        r#"{
+            "redirect_to_url": {
+                "return_url": "https://example.com/return",
+                "url": "https://hooks.stripe.com/redirect/authenticate/src_123?client_secret=src_client_secret_123"
+            },
+            "type": "redirect_to_url"
+        }"#,
+    ).unwrap();
+    match action {
+        NextAction::RedirectToUrl(redirect) => {
+            assert_eq!(redirect.url, "https://hooks.stripe.com/redirect/authenticate/src_123?client_secret=src_client_secret_123");
+            assert_eq!(redirect.return_url, Some("https://example.com/return".to_string()));
+        }
+        other => panic!("expected redirect_to_url, got {:?}", other),
+    }
+
+    let action: NextAction = json::from_str(
+        r#"{
+            "type": "verify_with_microdeposits",
+            "verify_with_microdeposits": {
+                "arrival_date": 1647586800,
+                "hosted_verification_url": "https://payments.stripe.com/microdeposit/pacs_test_123",
+                "microdeposit_type": "amounts"
+            }
+        }"#,
+    ).unwrap();
+    match action {
+        NextAction::VerifyWithMicrodeposits(verify) => {
+            assert_eq!(verify.arrival_date, 1647586800);
+            assert_eq!(verify.hosted_verification_url, "https://payments.stripe.com/microdeposit/pacs_test_123");
+        }
+        other => panic!("expected verify_with_microdeposits, got {:?}", other),
+    }
+
+    let action: NextAction = json::from_str(
+        r#"{"type": "pay_with_hologram", "pay_with_hologram": {"url": "https://example.com"}}"#,
+    ).unwrap();
+    assert_eq!(action.action_type(), NextActionType::Other);
+    match action {
+        NextAction::Other(value) => assert_eq!(value["pay_with_hologram"]["url"], "https://example.com"),
+        other => panic!("expected an unknown action, got {:?}", other),
+    }
+
+    let action: NextAction = json::from_str(r#"{"type": "use_stripe_sdk", "use_stripe_sdk": {"type": "three_d_secure_redirect"}}"#).unwrap();
+    assert_eq!(action.action_type(), NextActionType::UseStripeSdk);
+}
+
+#[test]
+fn serialize_bank_account_verify_params() {
+    use stripe::BankAccountVerifyParams;
+
+    let params = BankAccountVerifyParams { amounts: vec![32, 45] };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amounts[0]=32&amounts[1]=45");
+}
+
+#[test]
+fn serialize_verify_microdeposits_params() {
+    use stripe::{PaymentIntentVerifyMicrodepositsParams, SetupIntentVerifyMicrodepositsParams};
+
+    let params = PaymentIntentVerifyMicrodepositsParams {
+        amounts: Some(vec![32, 45]),
+        ..Default::default()
+    };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amounts[0]=32&amounts[1]=45");
+
+    let params = SetupIntentVerifyMicrodepositsParams {
+        descriptor_code: Some("SM11AA".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "descriptor_code=SM11AA");
+}
+
+#[test]
+fn serialize_pause_collection() {
+    use stripe::{PauseCollectionBehavior, SubscriptionParams, SubscriptionPauseCollection};
+
+    let mut params = SubscriptionParams::default();
+    params.pause_collection = Some(SubscriptionPauseCollection {
+        behavior: Some(PauseCollectionBehavior::MarkUncollectible),
+        resumes_at: Some(1504233902),
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "pause_collection[behavior]=mark_uncollectible&pause_collection[resumes_at]=1504233902"
+    );
+
+    let mut params = SubscriptionParams::default();
+    params.pause_collection = Some(SubscriptionPauseCollection { behavior: None, resumes_at: None });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "pause_collection=");
+}
+
+#[test]
+fn webhook_signature() {
+    use stripe::{Webhook, WebhookError};
+
+    // NOTE: Signed with "whsec_test" at t=1500000000, so the signature is valid but too old to accept
+    let payload = r#"{"id":"evt_123"}"#.to_string();
+    let header = "t=1500000000,v1=a451a5611c49e694c592f3fc63e161859c95ea8c4c0d9c3a6407009fdc14e9ee";
+    match Webhook::construct_event(payload.clone(), header.to_string(), "whsec_test".to_string()) {
+        Err(WebhookError::BadTimestamp(1500000000)) => {}
+        other => panic!("expected a bad timestamp error, got {:?}", other),
+    }
+
+    let tampered = header.replace("v1=a4", "v1=b4");
+    match Webhook::construct_event(payload.clone(), tampered, "whsec_test".to_string()) {
+        Err(WebhookError::BadSignature) => {}
+        other => panic!("expected a bad signature error, got {:?}", other),
+    }
+    match Webhook::construct_event(payload, header.to_string(), "whsec_other".to_string()) {
+        Err(WebhookError::BadSignature) => {}
+        other => panic!("expected a bad signature error, got {:?}", other),
+    }
+}
+
+#[test]
+fn lenient_currency() {
+    use stripe::Currency;
+    let currency = json::from_str::<Currency>("\"xyz\"").unwrap();
+    assert_eq!(currency, Currency::Other("xyz".to_string()));
+    assert_eq!(json::to_string(&currency).unwrap(), "\"xyz\"");
+    assert_eq!(format!("{}", currency), "xyz");
+}
+
+#[test]
+fn lenient_event_type() {
+    use stripe::EventType;
+    assert_eq!(json::from_str::<EventType>("\"charge.succeeded\"").unwrap(), EventType::ChargeSucceeded);
+    let event_type = json::from_str::<EventType>("\"hologram.projected\"").unwrap();
+    assert_eq!(event_type, EventType::Other("hologram.projected".to_string()));
+    assert_eq!(json::to_string(&event_type).unwrap(), "\"hologram.projected\"");
+}
+
+#[test]
+fn lenient_error_type() {
+    use stripe::ErrorType;
+    assert_eq!(json::from_str::<ErrorType>("\"made_up_error\"").unwrap(), ErrorType::Unknown);
+}
+
+#[test]
+fn lenient_error_code() {
+    use stripe::ErrorCode;
+    assert_eq!(json::from_str::<ErrorCode>("\"made_up_code\"").unwrap(), ErrorCode::Other);
+}
+
+#[test]
+fn lenient_decline_code() {
+    use stripe::DeclineCode;
+    assert_eq!(json::from_str::<DeclineCode>("\"made_up_code\"").unwrap(), DeclineCode::Other);
+}
+
+#[test]
+fn lenient_network_status() {
+    use stripe::NetworkStatus;
+    assert_eq!(json::from_str::<NetworkStatus>("\"made_up_status\"").unwrap(), NetworkStatus::Other);
+}
+
+#[test]
+fn lenient_risk_level() {
+    use stripe::RiskLevel;
+    assert_eq!(json::from_str::<RiskLevel>("\"unknown\"").unwrap(), RiskLevel::Unknown);
+    assert_eq!(json::from_str::<RiskLevel>("\"made_up_level\"").unwrap(), RiskLevel::Other);
+}
+
+#[test]
+fn lenient_outcome_type() {
+    use stripe::OutcomeType;
+    assert_eq!(json::from_str::<OutcomeType>("\"made_up_type\"").unwrap(), OutcomeType::Other);
+}
+
+#[test]
+fn lenient_source() {
+    use stripe::Source;
+    match json::from_str::<Source>(r#"{"object": "made_up_source", "id": "src_123"}"#).unwrap() {
+        Source::Other => {}
+        other => panic!("expected an unknown source, got {:?}", other),
+    }
+}
+
+#[test]
+fn lenient_event_object() {
+    use stripe::EventObject;
+    match json::from_str::<EventObject>(r#"{"object": "made_up_object", "id": "obj_123"}"#).unwrap() {
+        EventObject::Other => {}
+        other => panic!("expected an unknown object, got {:?}", other),
+    }
+}
+
+#[test]
+fn lenient_test_clock_status() {
+    use stripe::TestClockStatus;
+    assert_eq!(json::from_str::<TestClockStatus>("\"made_up_status\"").unwrap(), TestClockStatus::Other);
+}
+
+#[test]
+fn lenient_reader_status() {
+    use stripe::terminal::ReaderStatus;
+    assert_eq!(json::from_str::<ReaderStatus>("\"online\"").unwrap(), ReaderStatus::Online);
+    assert_eq!(json::from_str::<ReaderStatus>("\"made_up_status\"").unwrap(), ReaderStatus::Other);
+}
+
+#[test]
+fn serialize_reader_display_params() {
+    use stripe::Currency;
+    use stripe::terminal::{ReaderDisplayCart, ReaderDisplayLineItem, ReaderDisplayParams};
+    let params = ReaderDisplayParams {
+        display_type: "cart",
+        cart: Some(ReaderDisplayCart {
+            currency: Currency::USD,
+            line_items: vec![ReaderDisplayLineItem { amount: 500, description: "Coffee", quantity: 2 }],
+            tax: None,
+            total: 1000,
+        }),
+    };
+    let encoded = urldecode(qs::to_string(&params).unwrap());
+    assert_eq!(
+        encoded,
+        "type=cart&cart[currency]=usd&cart[line_items][0][amount]=500&cart[line_items][0][description]=Coffee&cart[line_items][0][quantity]=2&cart[total]=1000"
+    );
+}
+
+#[test]
+fn serialize_customer_session_params() {
+    use stripe::{CustomerSessionComponent, CustomerSessionComponents, CustomerSessionCreateParams};
+    let params = CustomerSessionCreateParams {
+        customer: "cus_123",
+        components: CustomerSessionComponents {
+            pricing_table: Some(CustomerSessionComponent { enabled: true }),
+            ..Default::default()
+        },
+    };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "customer=cus_123&components[pricing_table][enabled]=true");
+}
+
+#[test]
+fn serialize_recurring() {
+    use stripe::{AggregateUsage, Interval, PlanParams, Recurring, UsageType};
+
+    let mut params = PlanParams::default();
+    params.recurring = Some(Recurring::new(Interval::Month, 3));
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "interval=month&interval_count=3");
+
+    params.recurring = Some(Recurring {
+        interval: Interval::Month,
+        interval_count: 1,
+        usage_type: UsageType::Metered,
+        aggregate_usage: Some(AggregateUsage::LastDuringPeriod),
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "interval=month&interval_count=1&usage_type=metered&aggregate_usage=last_during_period"
+    );
+}
+
+#[test]
+fn deserialize_recurring() {
+    use stripe::{AggregateUsage, Interval, PriceRecurring, Recurring, UsageType};
+
+    let recurring: Recurring = json::from_str(r#"{"interval": "week", "interval_count": 2}"#).unwrap();
+    assert_eq!(recurring, Recurring::new(Interval::Week, 2));
+
+    let recurring: PriceRecurring = json::from_str(r#"{"interval": "year", "interval_count": 1}"#).unwrap();
+    assert_eq!(recurring, Recurring::new(Interval::Year, 1));
+
+    let recurring: Recurring = json::from_str(
+        r#"{"interval": "fortnight", "interval_count": 1, "usage_type": "metered", "aggregate_usage": "sum"}"#,
+    ).unwrap();
+    assert_eq!(recurring.interval, Interval::Other("fortnight".to_string()));
+    assert_eq!(recurring.usage_type, UsageType::Metered);
+    assert_eq!(recurring.aggregate_usage, Some(AggregateUsage::Sum));
+}
+
+#[test]
+fn deserialize_elevated_risk_outcome() {
+    use stripe::{ChargeOutcome, Expandable, OutcomeType, RiskLevel};
+
+    let outcome: ChargeOutcome = json::from_str(
+        r#"{
+            "network_status": "approved_by_network",
+            "reason": "elevated_risk_level",
+            "risk_level": "elevated",
+            "risk_score": 72,
+            "rule": "rule_1Abc",
+            "seller_message": "Stripe evaluated this payment as having elevated risk, and placed it in your manual review queue.",
+            "type": "manual_review"
+        }"#,
+    ).unwrap();
+    assert_eq!(outcome.outcome_type, OutcomeType::ManualReview);
+    assert_eq!(outcome.risk_level, Some(RiskLevel::Elevated));
+    match outcome.rule {
+        Some(Expandable::Id(ref id)) => assert_eq!(id, "rule_1Abc"),
+        ref other => panic!("expected a rule id, got {:?}", other),
+    }
+}
+
+#[test]
+fn deserialize_blocked_outcome() {
+    use stripe::{ChargeOutcome, FraudDetails, FraudReport, NetworkStatus, OutcomeType, RiskLevel};
+
+    let outcome: ChargeOutcome = json::from_str(
+        r#"{
+            "network_status": "not_sent_to_network",
+            "reason": "highest_risk_level",
+            "risk_level": "highest",
+            "risk_score": 94,
+            "rule": {"id": "block_if_high_risk", "action": "block", "predicate": ":risk_level: = 'highest'"},
+            "seller_message": "Stripe blocked this payment as too risky.",
+            "type": "blocked"
+        }"#,
+    ).unwrap();
+    assert_eq!(outcome.outcome_type, OutcomeType::Blocked);
+    assert_eq!(outcome.network_status, Some(NetworkStatus::NotSentToNetwork));
+    assert_eq!(outcome.risk_level, Some(RiskLevel::Highest));
+    let rule = outcome.rule.as_ref().and_then(|rule| rule.as_object()).unwrap();
+    assert_eq!(rule.action, "block");
+
+    let details: FraudDetails = json::from_str(r#"{"stripe_report": "fraudulent"}"#).unwrap();
+    assert_eq!(details.stripe_report, Some(FraudReport::Fraudulent));
+    assert_eq!(details.user_report, None);
+}
+
+#[test]
+fn serialize_fraud_report() {
+    use stripe::{ChargeParams, FraudDetails, FraudReport};
+
+    let mut params = ChargeParams::default();
+    params.fraud_details = Some(FraudDetails { user_report: Some(FraudReport::Fraudulent), stripe_report: None });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "fraud_details[user_report]=fraudulent");
+}
+
+#[test]
+fn serialize_price_tiers() {
+    use stripe::{BillingScheme, PriceParams, PriceTierParams, TiersMode, UpTo};
+
+    let mut params = PriceParams::default();
+    params.billing_scheme = Some(BillingScheme::Tiered);
+    params.tiers_mode = Some(TiersMode::Graduated);
+    params.tiers = Some(vec![
+        PriceTierParams { up_to: UpTo::Max(1000), flat_amount: None, unit_amount: Some(10) },
+        PriceTierParams { up_to: UpTo::Inf, flat_amount: Some(500), unit_amount: Some(5) },
+    ]);
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "billing_scheme=tiered&tiers[0][up_to]=1000&tiers[0][unit_amount]=10&tiers[1][up_to]=inf&tiers[1][flat_amount]=500&tiers[1][unit_amount]=5&tiers_mode=graduated"
+    );
+}
+
+#[test]
+fn deserialize_capability() {
+    use stripe::{Capability, CapabilityStatus};
+
+    let capability: Capability = json::from_str(
+        r#"{
+            "id": "card_payments",
+            "object": "capability",
+            "account": "acct_123",
+            "requested": true,
+            "requested_at": 1504233902,
+            "requirements": {
+                "current_deadline": null,
+                "currently_due": ["individual.dob.day"],
+                "disabled_reason": "requirements.fields_needed",
+                "errors": [],
+                "eventually_due": ["individual.dob.day"],
+                "past_due": [],
+                "pending_verification": []
+            },
+            "status": "inactive"
+        }"#,
+    ).unwrap();
+    assert_eq!(capability.status, CapabilityStatus::Inactive);
+    assert_eq!(capability.requirements.unwrap().currently_due, vec!["individual.dob.day".to_string()]);
+}
+
+#[test]
+fn serialize_billing_cycle_anchor() {
+    use stripe::{BillingCycleAnchor, SubscriptionParams};
+
+    let mut params = SubscriptionParams::default();
+    params.billing_cycle_anchor = Some(BillingCycleAnchor::Now);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "billing_cycle_anchor=now");
+
+    params.billing_cycle_anchor = Some(BillingCycleAnchor::Unchanged);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "billing_cycle_anchor=unchanged");
+
+    params.billing_cycle_anchor = Some(BillingCycleAnchor::Timestamp(1504233902));
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "billing_cycle_anchor=1504233902");
+}
+
+#[test]
+fn serialize_subscription_items() {
+    use stripe::{ItemParams, SubscriptionParams};
+
+    let mut params = SubscriptionParams::default();
+    params.items = Some(vec![
+        ItemParams { id: Some("si_123"), quantity: Some(2), ..Default::default() },
+        ItemParams { id: Some("si_456"), deleted: Some(true), ..Default::default() },
+        ItemParams { price: Some("price_789"), quantity: Some(1), ..Default::default() },
+    ]);
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "items[0][id]=si_123&items[0][quantity]=2\
+         &items[1][id]=si_456&items[1][deleted]=true\
+         &items[2][price]=price_789&items[2][quantity]=1"
+    );
+}
+
+#[test]
+fn proration_behavior() {
+    use stripe::{ProrationBehavior, SubscriptionParams};
+
+    let mut params = SubscriptionParams::default();
+    params.proration_behavior = Some(ProrationBehavior::None);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "proration_behavior=none");
+
+    params.proration_behavior = Some(ProrationBehavior::AlwaysInvoice);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "proration_behavior=always_invoice");
+
+    let behavior: ProrationBehavior = json::from_str(r#""create_prorations""#).unwrap();
+    assert_eq!(behavior, ProrationBehavior::CreateProrations);
+    let behavior: ProrationBehavior = json::from_str(r#""prorate_later""#).unwrap();
+    assert_eq!(behavior, ProrationBehavior::Other("prorate_later".to_string()));
+}
+
+#[test]
+fn serialize_subscription_trial_end() {
+    use stripe::{SubscriptionParams, SubscriptionTrialEnd};
+
+    let mut params = SubscriptionParams::default();
+    params.trial_end = Some(SubscriptionTrialEnd::Now);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "trial_end=now");
+
+    params.trial_end = Some(SubscriptionTrialEnd::Timestamp(1504233902));
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "trial_end=1504233902");
+}
+
+#[test]
+fn serialize_subscription_trials() {
+    use stripe::{MissingPaymentMethod, ProrationBehavior, SubscriptionParams, SubscriptionTrialEnd, TrialEndBehavior, TrialSettings};
+
+    // Ending the trial of an existing subscription immediately
+    let mut params = SubscriptionParams::default();
+    params.proration_behavior = Some(ProrationBehavior::None);
+    params.trial_end = Some(SubscriptionTrialEnd::Now);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "proration_behavior=none&trial_end=now");
+
+    // Starting a subscription with its plan's trial, cancelled if no card is added
+    let mut params = SubscriptionParams::default();
+    params.customer = Some("cus_123");
+    params.trial_from_plan = Some(true);
+    params.trial_settings = Some(TrialSettings {
+        end_behavior: TrialEndBehavior { missing_payment_method: MissingPaymentMethod::Cancel },
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "customer=cus_123&trial_from_plan=true&trial_settings[end_behavior][missing_payment_method]=cancel"
+    );
+
+    let settings: TrialSettings = json::from_str(r#"{"end_behavior": {"missing_payment_method": "create_invoice"}}"#).unwrap();
+    assert_eq!(settings.end_behavior.missing_payment_method, MissingPaymentMethod::CreateInvoice);
+    let settings: TrialSettings = json::from_str(r#"{"end_behavior": {"missing_payment_method": "archive"}}"#).unwrap();
+    assert_eq!(settings.end_behavior.missing_payment_method, MissingPaymentMethod::Other("archive".to_string()));
+}
+
+#[test]
+fn serialize_proration_preview_params() {
+    use stripe::{ProrationPreviewParams, SubscriptionItemParams};
+
+    let params = ProrationPreviewParams {
+        subscription: "sub_123",
+        items: vec![SubscriptionItemParams { id: Some("si_123"), price: Some("price_pro"), ..Default::default() }],
+        proration_date: Some(1504233902),
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "subscription=sub_123&subscription_items[0][id]=si_123&subscription_items[0][price]=price_pro&subscription_proration_date=1504233902"
+    );
+}
+
+#[test]
+fn proration_preview() {
+    use stripe::{Invoice, ProrationPreview};
+
+    let payload = r#"{
+            "id": null,
+            "amount_due": 3967,
+            "attempt_count": 0,
+            "attempted": false,
+            "closed": false,
+            "currency": "usd",
+            "customer": "cus_123",
+            "date": 1504233902,
+            "forgiven": false,
+            "lines": {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "ii_unused",
+                        "amount": -1033,
+                        "currency": "usd",
+                        "description": "Unused time on Basic after 01 Sep 2017",
+                        "discountable": false,
+                        "livemode": false,
+                        "metadata": {},
+                        "period": {"start": 1504233902, "end": 1506825902},
+                        "proration": true,
+                        "quantity": 1,
+                        "type": "invoiceitem"
+                    },
+                    {
+                        "id": "ii_remaining",
+                        "amount": 2000,
+                        "currency": "usd",
+                        "description": "Remaining time on Pro after 01 Sep 2017",
+                        "discountable": false,
+                        "livemode": false,
+                        "metadata": {},
+                        "period": {"start": 1504233902, "end": 1506825902},
+                        "proration": true,
+                        "quantity": 1,
+                        "type": "invoiceitem"
+                    },
+                    {
+                        "id": "ii_earlier",
+                        "amount": 500,
+                        "currency": "usd",
+                        "description": "Remaining time on Seats after 15 Aug 2017",
+                        "discountable": false,
+                        "livemode": false,
+                        "metadata": {},
+                        "period": {"start": 1502755200, "end": 1506825902},
+                        "proration": true,
+                        "quantity": 1,
+                        "type": "invoiceitem"
+                    },
+                    {
+                        "id": "sli_next",
+                        "amount": 2500,
+                        "currency": "usd",
+                        "description": "1 × Pro (at $25.00 / month)",
+                        "discountable": true,
+                        "livemode": false,
+                        "metadata": {},
+                        "period": {"start": 1506825902, "end": 1509504302},
+                        "proration": false,
+                        "quantity": 1,
+                        "type": "subscription"
+                    }
+                ],
+                "has_more": false,
+                "url": "/v1/invoices/upcoming/lines?customer=cus_123"
+            },
+            "livemode": false,
+            "metadata": {},
+            "paid": false,
+            "period_end": 1504233902,
+            "period_start": 1501555502,
+            "starting_balance": 0,
+            "subtotal": 3967,
+            "total": 3967
+        }"#;
+
+    let invoice: Invoice = json::from_str(payload).unwrap();
+    let preview = ProrationPreview::from_invoice(invoice, Some(1504233902)).unwrap();
+    assert_eq!(preview.immediate_total, 967);
+    let ids: Vec<_> = preview.proration_lines().iter().map(|line| line.id.as_str()).collect();
+    assert_eq!(ids, vec!["ii_unused", "ii_remaining"]);
+
+    let paginated: Invoice = json::from_str(&payload.replace(r#""has_more": false"#, r#""has_more": true"#)).unwrap();
+    assert!(ProrationPreview::from_invoice(paginated, Some(1504233902)).is_err());
+}
+
+#[test]
+fn serialize_account_reject_params() {
+    use stripe::{AccountRejectParams, AccountRejectReason};
+
+    let params = AccountRejectParams { reason: AccountRejectReason::TermsOfService };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "reason=terms_of_service");
+}
+
+#[test]
+fn deserialize_external_accounts() {
+    use stripe::{ExternalAccount, List};
+
+    let accounts: List<ExternalAccount> = json::from_str(
+        r#"{
+            "object": "list",
+            "data": [
+                {
+                    "id": "ba_123",
+                    "object": "bank_account",
+                    "account": "acct_123",
+                    "bank_name": "STRIPE TEST BANK",
+                    "country": "US",
+                    "currency": "usd",
+                    "default_for_currency": true,
+                    "fingerprint": "1JWtPxqbdX5Gamtc",
+                    "last4": "6789",
+                    "metadata": {},
+                    "routing_number": "110000000",
+                    "status": "new"
+                },
+                {
+                    "id": "card_123",
+                    "object": "card",
+                    "account": "acct_123",
+                    "brand": "Visa",
+                    "country": "US",
+                    "currency": "usd",
+                    "default_for_currency": false,
+                    "exp_month": 8,
+                    "exp_year": 2030,
+                    "fingerprint": "Xt5EWLLDS7FJjR1c",
+                    "funding": "debit",
+                    "last4": "5556",
+                    "metadata": {}
+                }
+            ],
+            "has_more": false,
+            "url": "/v1/accounts/acct_123/external_accounts"
+        }"#,
+    ).unwrap();
+
+    match accounts.data[0] {
+        ExternalAccount::BankAccount(ref bank_account) => assert_eq!(bank_account.default_for_currency, Some(true)),
+        ref other => panic!("expected a bank account, got {:?}", other),
+    }
+    match accounts.data[1] {
+        ExternalAccount::Card(ref card) => assert_eq!(card.last4, "5556"),
+        ref other => panic!("expected a card, got {:?}", other),
+    }
+
+    // NOTE: A bank account missing a field reports that field, rather than failing to match any variant
+    let err = json::from_str::<ExternalAccount>(r#"{"id": "ba_123", "object": "bank_account"}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field"), "{}", err);
+    assert!(json::from_str::<ExternalAccount>(r#"{"id": "alipay_123", "object": "alipay_account"}"#).is_err());
+}
+
+#[test]
+fn serialize_external_account_params() {
+    use stripe::{Currency, ExternalAccountParams, ExternalAccountSource, ExternalBankAccountParams};
+
+    let mut bank_account = ExternalBankAccountParams::new("000123456789", "US", Currency::USD);
+    bank_account.routing_number = Some("110000000");
+    let params = ExternalAccountParams {
+        external_account: ExternalAccountSource::BankAccount(bank_account),
+        default_for_currency: Some(true),
+        metadata: None,
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "external_account[object]=bank_account&external_account[account_number]=000123456789\
+         &external_account[country]=US&external_account[currency]=usd\
+         &external_account[routing_number]=110000000&default_for_currency=true"
+    );
+}
+
+#[test]
+fn serialize_account_settings() {
+    use stripe::{AccountSettings, AccountUpdateParams, PayoutScheduleDetails, PayoutSettings};
+
+    let params = AccountUpdateParams {
+        settings: Some(AccountSettings {
+            payouts: Some(PayoutSettings {
+                schedule: Some(PayoutScheduleDetails {
+                    interval: Some("manual".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "settings[payouts][schedule][interval]=manual");
+}
+
+#[test]
+fn serialize_person_params() {
+    use stripe::{DateOfBirth, PersonCreateParams, RelationshipParams};
+
+    let params = PersonCreateParams {
+        dob: Some(DateOfBirth { day: 1, month: 2, year: 1980 }),
+        first_name: Some("Jenny"),
+        relationship: Some(RelationshipParams {
+            owner: Some(true),
+            percent_ownership: Some(51.5),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "dob[day]=1&dob[month]=2&dob[year]=1980&first_name=Jenny\
+         &relationship[owner]=true&relationship[percent_ownership]=51.5"
+    );
+}
+
+#[test]
+fn serialize_payment_method_options() {
+    use stripe::{CardPaymentMethodOptions, PaymentIntentParams, PaymentMethodOptions, RequestThreeDSecure};
+
+    let mut params = PaymentIntentParams::default();
+    params.amount = Some(250000);
+    params.payment_method_options = Some(PaymentMethodOptions {
+        card: Some(CardPaymentMethodOptions {
+            request_three_d_secure: Some(RequestThreeDSecure::Any),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount=250000&payment_method_options[card][request_three_d_secure]=any"
+    );
+}
+
+#[test]
+fn serialize_payment_method_options_by_type() {
+    use stripe::{AcssDebitMandateOptions, AcssDebitPaymentMethodOptions, CardInstallmentsOptions, CardPaymentMethodOptions};
+    use stripe::{InstallmentPlan, Interval, PaymentMethodOptions, UsBankAccountPaymentMethodOptions, VerificationMethod};
+
+    let options = PaymentMethodOptions {
+        card: Some(CardPaymentMethodOptions {
+            installments: Some(CardInstallmentsOptions {
+                enabled: Some(true),
+                plan: Some(InstallmentPlan { count: 3, interval: Interval::Month, plan_type: "fixed_count" }),
+            }),
+            network: Some("cartes_bancaires"),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&options).unwrap()),
+        "card[installments][enabled]=true&card[installments][plan][count]=3\
+         &card[installments][plan][interval]=month&card[installments][plan][type]=fixed_count&card[network]=cartes_bancaires"
+    );
+
+    let options = PaymentMethodOptions {
+        acss_debit: Some(AcssDebitPaymentMethodOptions {
+            mandate_options: Some(AcssDebitMandateOptions {
+                payment_schedule: Some("sporadic"),
+                transaction_type: Some("personal"),
+                ..Default::default()
+            }),
+            verification_method: Some(VerificationMethod::Microdeposits),
+            ..Default::default()
+        }),
+        us_bank_account: Some(UsBankAccountPaymentMethodOptions {
+            verification_method: Some(VerificationMethod::Instant),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        urldecode(qs::to_string(&options).unwrap()),
+        "acss_debit[mandate_options][payment_schedule]=sporadic&acss_debit[mandate_options][transaction_type]=personal\
+         &acss_debit[verification_method]=microdeposits&us_bank_account[verification_method]=instant"
+    );
+}
+
+#[test]
+fn deserialize_topup() {
+    use stripe::{Topup, TopupStatus};
+
+    let topup: Topup = json::from_str(
+        r#"{
+            "id": "tu_123",
+            "object": "topup",
+            "amount": 10000,
+            "balance_transaction": null,
+            "created": 1500000000,
+            "currency": "usd",
+            "description": "Top-up for week of May 31",
+            "expected_availability_date": 1500400000,
+            "failure_code": null,
+            "failure_message": null,
+            "livemode": false,
+            "metadata": {},
+            "source": null,
+            "statement_descriptor": null,
+            "status": "pending",
+            "transfer_group": null
+        }"#,
+    ).unwrap();
+    assert_eq!(topup.status, TopupStatus::Pending);
+    assert_eq!(topup.expected_availability_date, Some(1500400000));
+}
+
+#[test]
+fn serialize_payment_method_data() {
+    use stripe::{BillingDetails, PaymentIntentParams, PaymentMethodCardParams, PaymentMethodData, PaymentMethodSepaDebitParams};
+
+    let mut params = PaymentIntentParams::default();
+    params.payment_method_data = Some(PaymentMethodData::Card(PaymentMethodCardParams {
+        number: "4242424242424242",
+        exp_month: 12,
+        exp_year: 2030,
+        cvc: Some("123"),
+        billing_details: None,
+    }));
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "payment_method_data[type]=card&payment_method_data[card][number]=4242424242424242\
+         &payment_method_data[card][exp_month]=12&payment_method_data[card][exp_year]=2030\
+         &payment_method_data[card][cvc]=123"
+    );
+
+    let mut params = PaymentIntentParams::default();
+    params.payment_method_data = Some(PaymentMethodData::SepaDebit(PaymentMethodSepaDebitParams {
+        iban: "DE89370400440532013000",
+        billing_details: Some(BillingDetails { name: Some("Jenny Rosen".to_string()), ..Default::default() }),
+    }));
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "payment_method_data[type]=sepa_debit&payment_method_data[billing_details][name]=Jenny+Rosen\
+         &payment_method_data[sepa_debit][iban]=DE89370400440532013000"
+    );
+}
+
+#[test]
+fn balance_transaction_fees() {
+    use stripe::{BalanceTransaction, Currency, FeeType};
+
+    let txn: BalanceTransaction = json::from_str(
+        r#"{
+            "id": "txn_123",
+            "object": "balance_transaction",
+            "amount": 10000,
+            "available_on": 1500000000,
+            "created": 1500000000,
+            "currency": "usd",
+            "description": null,
+            "fee": 620,
+            "fee_details": [
+                {"amount": 320, "application": null, "currency": "usd", "description": "Stripe processing fees", "type": "stripe_fee"},
+                {"amount": 300, "application": "ca_123", "currency": "usd", "description": "Application fee", "type": "application_fee"},
+                {"amount": 25, "application": null, "currency": "eur", "description": "Currency conversion", "type": "stripe_fee"},
+                {"amount": 0, "application": null, "currency": "usd", "description": "Climate", "type": "climate_fee"}
+            ],
+            "net": 9380,
+            "source": {"object": "charge", "id": "ch_123"},
+            "status": "available",
+            "type": "charge"
+        }"#,
+    ).unwrap();
+    let fee_types: Vec<_> = txn.fee_details.iter().map(|fee| fee.fee_type).collect();
+    assert_eq!(fee_types, vec![FeeType::StripeFee, FeeType::ApplicationFee, FeeType::StripeFee, FeeType::Other]);
+    assert_eq!(txn.stripe_fee(), 320);
+    assert_eq!(txn.application_fee(), 300);
+    assert!(txn.net_matches());
+    let foreign = txn.foreign_fee_details();
+    assert_eq!(foreign.len(), 1);
+    assert_eq!(foreign[0].currency, Currency::EUR);
+}
+
+#[test]
+fn serialize_checkout_session_price_data() {
+    use stripe::{CheckoutLineItemPriceData, CheckoutLineItemProductData, CheckoutSessionLineItem, CheckoutSessionParams};
+    use stripe::{Currency, Interval, Recurring};
+
+    let params = CheckoutSessionParams {
+        success_url: "https://example.com/success",
+        line_items: Some(vec![CheckoutSessionLineItem {
+            price_data: Some(CheckoutLineItemPriceData {
+                currency: Currency::USD,
+                product: None,
+                product_data: Some(CheckoutLineItemProductData { name: "Gold plan", ..Default::default() }),
+                recurring: Some(Recurring::new(Interval::Month, 1)),
+                unit_amount: Some(1500),
+            }),
+            quantity: Some(2),
+            ..Default::default()
+        }]),
+        mode: Some("subscription"),
+        ..Default::default()
+    };
+    let encoded = urldecode(qs::to_string(&params).unwrap());
+    assert!(encoded.starts_with("success_url="));
+    assert_eq!(
+        encoded.splitn(2, '&').nth(1).unwrap(),
+        "line_items[0][price_data][currency]=usd&line_items[0][price_data][product_data][name]=Gold+plan\
+         &line_items[0][price_data][recurring][interval]=month&line_items[0][price_data][recurring][interval_count]=1\
+         &line_items[0][price_data][unit_amount]=1500&line_items[0][quantity]=2&mode=subscription"
+    );
+}
+
+#[test]
+fn serialize_deeply_nested_params() {
+    use stripe::{CheckoutLineItemPriceData, CheckoutLineItemProductData, CheckoutSessionLineItem, CheckoutSessionParams};
+    use stripe::{Currency, Metadata};
+
+    let mut metadata = Metadata::new();
+    metadata.insert("sku".to_string(), "gold-1".to_string());
+    let params = CheckoutSessionParams {
+        success_url: "https://example.com/success",
+        line_items: Some(vec![CheckoutSessionLineItem {
+            price_data: Some(CheckoutLineItemPriceData {
+                currency: Currency::USD,
+                product: None,
+                product_data: Some(CheckoutLineItemProductData {
+                    name: "Gold plan",
+                    images: Some(vec!["gold.png"]),
+                    metadata: Some(metadata),
+                    ..Default::default()
+                }),
+                recurring: None,
+                unit_amount: Some(1500),
+            }),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let encoded = urldecode(qs::to_string(&params).unwrap());
+    assert!(encoded.contains("&line_items[0][price_data][product_data][images][0]=gold.png&"));
+    assert!(encoded.contains("&line_items[0][price_data][product_data][metadata][sku]=gold-1&"));
+}
+
+#[test]
+fn destination_charges() {
+    use stripe::{ChargeParams, ErrorType, RequestError, TransferData, TransferDataParams};
+
+    let mut params = ChargeParams::default();
+    params.amount = Some(1000);
+    params.application_fee_amount = Some(123);
+    params.transfer_data = Some(TransferDataParams { destination: "acct_123", amount: None });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount=1000&application_fee_amount=123&transfer_data[destination]=acct_123"
+    );
+    params.transfer_data = Some(TransferDataParams { destination: "acct_123", amount: Some(877) });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount=1000&application_fee_amount=123&transfer_data[destination]=acct_123&transfer_data[amount]=877"
+    );
+
+    let data: TransferData = json::from_str(r#"{"destination": "acct_123"}"#).unwrap();
+    assert_eq!(data.amount, None);
+    let data: TransferData = json::from_str(r#"{"amount": 877, "destination": "acct_123"}"#).unwrap();
+    assert_eq!(data.amount, Some(877));
+
+    let err: RequestError = json::from_str(
+        r#"{
+            "type": "invalid_request_error",
+            "message": "The application fee amount must not exceed the charge amount.",
+            "param": "application_fee_amount"
+        }"#,
+    ).unwrap();
+    assert_eq!(err.error_type, ErrorType::InvalidRequest);
+    assert_eq!(err.param, Some("application_fee_amount".to_string()));
+}
+
+#[test]
+fn serialize_transfer_data_updates() {
+    use stripe::{CaptureParams, PaymentIntentCaptureParams, PaymentIntentIncrementalAuthorizationParams, TransferDataUpdateParams};
+
+    let mut params = CaptureParams::default();
+    params.transfer_data = Some(TransferDataUpdateParams { amount: Some(800) });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "transfer_data[amount]=800");
+
+    let mut params = PaymentIntentCaptureParams::default();
+    params.amount_to_capture = Some(1000);
+    params.transfer_data = Some(TransferDataUpdateParams { amount: Some(800) });
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amount_to_capture=1000&transfer_data[amount]=800");
+
+    let mut params = PaymentIntentIncrementalAuthorizationParams::default();
+    params.amount = 1500;
+    params.transfer_data = Some(TransferDataUpdateParams::default());
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "amount=1500");
+}
+
+#[test]
+fn serialize_invoice_collection() {
+    use stripe::{CollectionMethod, InvoiceParams};
+
+    let mut params = InvoiceParams::default();
+    params.customer = Some("cus_123");
+    params.collection_method = Some(CollectionMethod::SendInvoice);
+    params.days_until_due = Some(30);
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "collection_method=send_invoice&customer=cus_123&days_until_due=30"
+    );
+
+    let mut params = InvoiceParams::default();
+    params.auto_advance = Some(false);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "auto_advance=false");
+}
+
+#[test]
+fn deserialize_invoice_collection() {
+    use stripe::{AutomaticTaxStatus, CollectionMethod, Invoice};
+
+    let invoice: Invoice = json::from_str(
+        r#"{
+            "id": "in_123",
+            "amount_due": 1000,
+            "attempt_count": 0,
+            "attempted": false,
+            "auto_advance": false,
+            "automatic_tax": {"enabled": true, "status": "requires_location_inputs"},
+            "closed": false,
+            "collection_method": "send_invoice",
+            "currency": "usd",
+            "customer": "cus_123",
+            "date": 1504233902,
+            "days_until_due": 30,
+            "ending_balance": null,
+            "forgiven": false,
+            "lines": {"object": "list", "data": [], "has_more": false, "total_count": 0, "url": "/v1/invoices/in_123/lines"},
+            "livemode": false,
+            "metadata": {},
+            "paid": false,
+            "paid_out_of_band": false,
+            "period_end": 1504233902,
+            "period_start": 1504233902,
+            "starting_balance": -500,
+            "subtotal": 1000,
+            "total": 1000
+        }"#,
+    ).unwrap();
+    assert_eq!(invoice.collection_method, Some(CollectionMethod::SendInvoice));
+    assert_eq!(invoice.days_until_due, Some(30));
+    assert_eq!(invoice.auto_advance, Some(false));
+    assert_eq!(invoice.starting_balance, -500);
+    let automatic_tax = invoice.automatic_tax.unwrap();
+    assert!(automatic_tax.enabled);
+    assert_eq!(automatic_tax.status, Some(AutomaticTaxStatus::RequiresLocationInputs));
+
+    let method: CollectionMethod = json::from_str(r#""pay_later""#).unwrap();
+    assert_eq!(method, CollectionMethod::Other("pay_later".to_string()));
+}
+
+#[test]
+fn customer_default_payment_method_id() {
+    use stripe::Customer;
+
+    let customer = |invoice_settings: &str| -> Customer {
+        json::from_str(&format!(
+            r#"{{
+                "id": "cus_123",
+                "account_balance": 0,
+                "business_vat_id": null,
+                "created": 1504233902,
+                "currency": "usd",
+                "default_source": "card_legacy",
+                "delinquent": false,
+                "desc": null,
+                "discount": null,
+                "email": null,
+                "invoice_settings": {},
+                "livemode": false,
+                "metadata": {{}},
+                "shipping": null,
+                "sources": {{"object": "list", "data": [], "has_more": false, "url": "/v1/customers/cus_123/sources"}},
+                "subscriptions": {{"object": "list", "data": [], "has_more": false, "url": "/v1/customers/cus_123/subscriptions"}}
+            }}"#,
+            invoice_settings
+        )).unwrap()
+    };
+    assert_eq!(customer(r#"{"default_payment_method": "pm_123"}"#).default_payment_method_id(), Some("pm_123"));
+    assert_eq!(customer(r#"{"default_payment_method": null}"#).default_payment_method_id(), Some("card_legacy"));
+    assert_eq!(customer("null").default_payment_method_id(), Some("card_legacy"));
+}
+
+#[test]
+fn event_api_version() {
+    use stripe::{Event, DEFAULT_API_VERSION};
+
+    let event = |api_version: &str| -> Event {
+        json::from_str(&format!(
+            r#"{{"type": "customer.created", "api_version": {}, "data": {{"object": {{"object": "unknown"}}}}, "livemode": false}}"#,
+            api_version
+        )).unwrap()
+    };
+    assert!(!event(&format!("{:?}", DEFAULT_API_VERSION)).api_version_differs(DEFAULT_API_VERSION));
+    assert!(event(r#""2015-10-16""#).api_version_differs(DEFAULT_API_VERSION));
+    assert!(event(r#""2099-01-01""#).api_version_differs(DEFAULT_API_VERSION));
+    assert!(!event("null").api_version_differs(DEFAULT_API_VERSION));
+    assert!(!event(r#""2015-10-16""#).api_version_differs("2015-10-16"));
+}
+
+#[cfg(feature = "test-helpers")]
+#[test]
+fn mock_event_round_trip() {
+    use stripe::{EventObject, EventType, Webhook};
+    use stripe::test_helpers::{fixture, mock_event};
+
+    let secret = "whsec_test_123";
+    for &event_type in &["invoice.payment_succeeded", "customer.subscription.deleted", "charge.refunded"] {
+        let (payload, header) = mock_event(event_type, fixture(event_type).unwrap(), secret);
+        let event = Webhook::construct_event(payload, header, secret.to_string()).unwrap();
+        assert!(!event.api_version_mismatch);
+        match (event.event_type, event.data.object) {
+            (EventType::InvoicePaymentSucceeded, EventObject::Invoice(invoice)) => assert_eq!(invoice.id, Some("in_test_123".to_string())),
+            (EventType::CustomerSubscriptionDeleted, EventObject::Subscription(sub)) => assert_eq!(sub.status, stripe::SubscriptionStatus::Canceled),
+            (EventType::ChargeRefunded, EventObject::Charge(charge)) => assert!(charge.refunded),
+            (event_type, object) => panic!("unexpected {:?} event with {:?}", event_type, object),
+        }
+    }
+
+    let (payload, header) = mock_event("charge.refunded", fixture("charge.refunded").unwrap(), secret);
+    match Webhook::construct_event(payload, header, "whsec_other".to_string()) {
+        Err(stripe::WebhookError::BadSignature) => {}
+        other => panic!("expected a bad signature error, got {:?}", other.map(|event| event.event_type)),
+    }
+    assert_eq!(fixture("customer.created"), None);
+}
+
+#[test]
+fn charge_refunds() {
+    use stripe::Charge;
+
+    let charge = |amount_refunded: u64| -> Charge {
+        json::from_str(&format!(
+            r#"{{
+                "id": "ch_123", "amount": 500, "amount_refunded": {}, "captured": true, "created": 1500000000,
+                "currency": "usd", "fraud_details": {{}}, "livemode": false, "metadata": {{}}, "paid": true,
+                "refunded": {},
+                "refunds": {{
+                    "data": [{{
+                        "id": "re_123", "object": "refund", "amount": {}, "balance_transaction": "txn_123",
+                        "charge": "ch_123", "created": 1500000100, "currency": "usd", "metadata": {{}},
+                        "reason": null, "receipt_number": null, "status": "succeeded"
+                    }}],
+                    "has_more": false,
+                    "url": "/v1/charges/ch_123/refunds"
+                }},
+                "source": {{"object": "bitcoin_receiver"}}, "status": "succeeded"
+            }}"#,
+            amount_refunded, amount_refunded == 500, amount_refunded
+        )).unwrap()
+    };
+
+    let partial = charge(200);
+    assert!(!partial.refunded);
+    assert!(!partial.is_fully_refunded());
+    assert_eq!(partial.refunds.data[0].amount, 200);
+
+    let full = charge(500);
+    assert!(full.refunded);
+    assert!(full.is_fully_refunded());
+}
+
+#[test]
+fn error_param_path() {
+    use stripe::{ParamPath, ParamSegment, RequestError};
+
+    let path = ParamPath::parse("items[0][price]");
+    assert_eq!(path.as_str(), "items[0][price]");
+    assert_eq!(path.segments(), &[
+        ParamSegment::Field("items".to_string()),
+        ParamSegment::Index(0),
+        ParamSegment::Field("price".to_string()),
+    ][..]);
+    assert!(path.matches("items[].price"));
+    assert!(path.matches("items[][price]"));
+    assert!(path.matches("items[0].price"));
+    assert!(!path.matches("items[1].price"));
+    assert!(!path.matches("items.price"));
+    assert!(!path.matches("items[]"));
+
+    let path = ParamPath::parse("shipping[address][line1]");
+    assert_eq!(path.segments().len(), 3);
+    assert!(path.matches("shipping.address.line1"));
+
+    let path = ParamPath::parse("currency");
+    assert_eq!(path.segments(), &[ParamSegment::Field("currency".to_string())][..]);
+    assert!(path.matches("currency"));
+
+    let path = ParamPath::parse("tiers[2][3][up_to]");
+    assert_eq!(&path.segments()[1..3], &[ParamSegment::Index(2), ParamSegment::Index(3)][..]);
+    assert!(path.matches("tiers[][].up_to"));
+    assert!(!path.matches("tiers[].up_to"));
+
+    let path = ParamPath::parse("metadata[unclosed");
+    assert_eq!(path.to_string(), "metadata[unclosed");
+    assert_eq!(path.segments()[1], ParamSegment::Field("[unclosed".to_string()));
+
+    let err: RequestError = json::from_str(
+        r#"{"type": "invalid_request_error", "message": "No such price: 'price_123'", "param": "items[0][price]"}"#,
+    ).unwrap();
+    assert!(err.param_matches("items[].price"));
+    assert_eq!(err.param_path().unwrap().segments()[1], ParamSegment::Index(0));
+    assert!(!RequestError::default().param_matches("items[].price"));
+}
+
+#[test]
+fn status_enums() {
+    use stripe::{InvoiceListParams, InvoiceStatus, PaymentIntentStatus, SetupIntentStatus, SubscriptionListParams, SubscriptionStatus};
+
+    assert_eq!(json::from_str::<PaymentIntentStatus>(r#""requires_capture""#).unwrap(), PaymentIntentStatus::RequiresCapture);
+    assert_eq!(json::from_str::<PaymentIntentStatus>(r#""made_up""#).unwrap(), PaymentIntentStatus::Other);
+    assert_eq!(json::from_str::<SetupIntentStatus>(r#""requires_action""#).unwrap(), SetupIntentStatus::RequiresAction);
+    assert_eq!(json::from_str::<SubscriptionStatus>(r#""past_due""#).unwrap(), SubscriptionStatus::PastDue);
+    assert_eq!(json::from_str::<InvoiceStatus>(r#""uncollectible""#).unwrap(), InvoiceStatus::Uncollectible);
+
+    let intent = |status: &str| -> stripe::PaymentIntent {
+        json::from_str(&format!(
+            r#"{{
+                "id": "pi_123", "object": "payment_intent", "amount": 2000, "amount_capturable": 0,
+                "amount_received": 0, "capture_method": "automatic", "confirmation_method": "automatic",
+                "created": 1500000000, "currency": "usd", "livemode": false, "metadata": {{}},
+                "payment_method_types": ["card"], "status": "{}"
+            }}"#,
+            status
+        )).unwrap()
+    };
+    assert!(intent("succeeded").is_terminal());
+    assert!(intent("canceled").is_terminal());
+    assert!(!intent("requires_action").is_terminal());
+
+    let mut params = SubscriptionListParams::default();
+    params.status = Some(SubscriptionStatus::All);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "status=all");
+
+    let mut params = InvoiceListParams::default();
+    params.status = Some(InvoiceStatus::Open);
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "status=open");
+}
+
+#[test]
+fn deserialize_checkout_session_expansions() {
+    use stripe::{CheckoutSession, Expandable, PaymentIntentStatus};
+
+    let session = |payment_intent: &str, line_items: &str| -> CheckoutSession {
+        json::from_str(&format!(
+            r#"{{
+                "id": "cs_test_123", "object": "checkout.session", "amount_total": 2000, "currency": "usd",
+                "livemode": false, "metadata": {{}}, "mode": "payment", "payment_status": "paid",
+                "status": "complete", "payment_intent": {}{}
+            }}"#,
+            payment_intent, line_items
+        )).unwrap()
+    };
+
+    let unexpanded = session(r#""pi_123""#, "");
+    assert!(unexpanded.is_paid());
+    assert_eq!(unexpanded.amount_total(), Some(2000));
+    assert!(unexpanded.line_items.is_none());
+    match unexpanded.payment_intent {
+        Some(Expandable::Id(ref id)) => assert_eq!(id, "pi_123"),
+        ref other => panic!("expected an unexpanded payment intent, got {:?}", other),
+    }
+
+    let expanded = session(
+        r#"{
+            "id": "pi_123", "object": "payment_intent", "amount": 2000, "amount_capturable": 0,
+            "amount_received": 2000, "capture_method": "automatic", "confirmation_method": "automatic",
+            "created": 1500000000, "currency": "usd", "livemode": false, "metadata": {},
+            "payment_method_types": ["card"], "status": "succeeded"
+        }"#,
+        r#", "line_items": {
+            "object": "list",
+            "data": [{
+                "id": "li_123", "object": "item", "amount_subtotal": 2000, "amount_total": 2000,
+                "currency": "usd", "description": "T-shirt", "quantity": 2,
+                "price": {
+                    "id": "price_123", "object": "price", "active": true, "billing_scheme": "per_unit",
+                    "created": 1500000000, "currency": "usd", "livemode": false, "metadata": {},
+                    "product": "prod_123", "type": "one_time", "unit_amount": 1000
+                }
+            }],
+            "has_more": false,
+            "url": "/v1/checkout/sessions/cs_test_123/line_items"
+        }"#,
+    );
+    match expanded.payment_intent {
+        Some(Expandable::Object(ref intent)) => assert_eq!(intent.status, PaymentIntentStatus::Succeeded),
+        ref other => panic!("expected an expanded payment intent, got {:?}", other),
+    }
+    let line_items = expanded.line_items.unwrap();
+    assert_eq!(line_items.data[0].quantity, Some(2));
+    assert_eq!(line_items.data[0].description, Some("T-shirt".to_string()));
+    assert_eq!(line_items.data[0].price.as_ref().unwrap().unit_amount, Some(1000));
+}
+
+#[test]
+fn deserialize_charge_payment_method_details() {
+    use stripe::PaymentMethodDetails;
+
+    let details: PaymentMethodDetails = json::from_str(
+        r#"{
+            "type": "card",
+            "card": {
+                "brand": "visa", "country": "US", "exp_month": 8, "exp_year": 2030, "funding": "credit",
+                "last4": "3220", "network": "visa",
+                "three_d_secure": {"authenticated": true, "succeeded": true, "version": "2.1.0"},
+                "wallet": null
+            }
+        }"#,
+    ).unwrap();
+    match details {
+        PaymentMethodDetails::Card(card) => {
+            assert_eq!(card.network, Some("visa".to_string()));
+            assert_eq!(card.three_d_secure.unwrap().succeeded, Some(true));
+            assert!(card.wallet.is_none());
+        }
+        other => panic!("expected card details, got {:?}", other),
+    }
+
+    let details: PaymentMethodDetails = json::from_str(
+        r#"{
+            "type": "card",
+            "card": {
+                "brand": "mastercard", "last4": "4444", "network": "mastercard", "three_d_secure": null,
+                "wallet": {"type": "apple_pay", "dynamic_last4": "9876", "apple_pay": {}}
+            }
+        }"#,
+    ).unwrap();
+    match details {
+        PaymentMethodDetails::Card(card) => {
+            let wallet = card.wallet.unwrap();
+            assert_eq!(wallet.wallet_type, "apple_pay");
+            assert_eq!(wallet.dynamic_last4, Some("9876".to_string()));
+        }
+        other => panic!("expected card details, got {:?}", other),
+    }
+
+    let details: PaymentMethodDetails = json::from_str(
+        r#"{
+            "type": "sepa_debit",
+            "sepa_debit": {
+                "bank_code": "37040044", "branch_code": null, "country": "DE", "fingerprint": "fp_123",
+                "last4": "3000", "mandate": "mandate_123"
+            }
+        }"#,
+    ).unwrap();
+    match details {
+        PaymentMethodDetails::SepaDebit(sepa_debit) => assert_eq!(sepa_debit.mandate, Some("mandate_123".to_string())),
+        other => panic!("expected sepa debit details, got {:?}", other),
+    }
+
+    let details: PaymentMethodDetails = json::from_str(r#"{"type": "klarna", "klarna": {"payment_method_category": "pay_later"}}"#).unwrap();
+    match details {
+        PaymentMethodDetails::Other(method_type, details) => {
+            assert_eq!(method_type, "klarna");
+            assert_eq!(details["payment_method_category"], "pay_later");
+        }
+        other => panic!("expected other details, got {:?}", other),
+    }
+
+    let malformed = json::from_str::<PaymentMethodDetails>(r#"{"type": "card", "card": {"brand": "visa", "exp_month": "twelve"}}"#);
+    assert!(malformed.is_err());
+}
+
+#[test]
+fn deserialize_total_details() {
+    use stripe::CheckoutSession;
+
+    let session = |total_details: &str| -> CheckoutSession {
+        json::from_str(&format!(
+            r#"{{
+                "id": "cs_test_123", "object": "checkout.session", "amount_total": 1980, "currency": "usd",
+                "livemode": false, "metadata": {{}}, "mode": "payment", "payment_status": "paid",
+                "status": "complete", "payment_intent": "pi_123", "total_details": {}
+            }}"#,
+            total_details
+        )).unwrap()
+    };
+
+    let unexpanded = session(r#"{"amount_discount": 200, "amount_shipping": 0, "amount_tax": 180}"#);
+    let totals = unexpanded.total_details.unwrap();
+    assert_eq!(totals.amount_discount, 200);
+    assert_eq!(totals.amount_tax, 180);
+    assert!(totals.breakdown.is_none());
+
+    let expanded = session(
+        r#"{
+            "amount_discount": 200, "amount_shipping": 0, "amount_tax": 180,
+            "breakdown": {
+                "discounts": [{
+                    "amount": 200,
+                    "discount": {
+                        "coupon": {
+                            "id": "10OFF", "object": "coupon", "amount_off": null, "created": 1500000000,
+                            "currency": null, "duration": "once", "duration_in_months": null, "livemode": false,
+                            "max_redemptions": null, "metadata": {}, "percent_off": 10, "redeem_by": 1600000000,
+                            "redeemed": 1, "valid": true, "deleted": false
+                        },
+                        "customer": "cus_123", "subscription": null, "start": 1500000000, "end": null
+                    }
+                }],
+                "taxes": [{
+                    "amount": 180,
+                    "rate": {
+                        "id": "txr_123", "object": "tax_rate", "active": true, "country": "US", "created": 1500000000,
+                        "description": null, "display_name": "Sales Tax", "inclusive": false, "jurisdiction": "CA",
+                        "livemode": false, "metadata": {}, "percentage": 10.0, "state": "CA", "tax_type": "sales_tax"
+                    },
+                    "taxability_reason": "standard_rated", "taxable_amount": 1800
+                }]
+            }
+        }"#,
+    );
+    let breakdown = expanded.total_details.unwrap().breakdown.unwrap();
+    assert_eq!(breakdown.discounts[0].amount, 200);
+    assert_eq!(breakdown.discounts[0].discount.coupon.id, "10OFF");
+    assert_eq!(breakdown.taxes[0].rate.id, "txr_123");
+    assert_eq!(breakdown.taxes[0].taxable_amount, Some(1800));
+}
+
+#[test]
+fn payout_reconciliation() {
+    use stripe::{BalanceTransaction, Payout, PayoutReconciliation, ReconciliationTotals};
+
+    let txn = |id: String, txn_type: &str, currency: &str, amount: i64, fee: i64| -> String {
+        format!(
+            r#"{{
+                "id": "{}", "object": "balance_transaction", "amount": {}, "available_on": 1500000000,
+                "created": 1500000000, "currency": "{}", "description": null, "fee": {}, "fee_details": [],
+                "net": {}, "source": {{"object": "charge", "id": "ch_123"}}, "status": "available", "type": "{}"
+            }}"#,
+            id, amount, currency, fee, amount - fee, txn_type
+        )
+    };
+    let mut fixture = Vec::new();
+    for i in 0..120 {
+        fixture.push(txn(format!("txn_ch_{}", i), "charge", "usd", 1000, 59));
+    }
+    for i in 0..20 {
+        fixture.push(txn(format!("txn_re_{}", i), "refund", "usd", -500, 0));
+    }
+    for i in 0..10 {
+        fixture.push(txn(format!("txn_dp_{}", i), "adjustment", "usd", -1500, 1500));
+    }
+    fixture.push(txn("txn_po".to_string(), "payout", "usd", -72920, 0));
+    let transactions: Vec<BalanceTransaction> = json::from_str(&format!("[{}]", fixture.join(","))).unwrap();
+
+    let payout: Payout = json::from_str(
+        r#"{
+            "id": "po_123", "object": "payout", "amount": 72920, "arrival_date": 1500000000,
+            "balance_transaction": "txn_po", "created": 1500000000, "currency": "usd", "description": "STRIPE PAYOUT",
+            "destination": "ba_123", "failure_balance_transaction": null, "failure_code": null,
+            "failure_message": null, "livemode": false, "metadata": {}, "method": "standard",
+            "source_type": "card", "statement_descriptor": null, "status": "paid", "type": "bank_account"
+        }"#,
+    ).unwrap();
+
+    let reconciliation = PayoutReconciliation::new(&payout, &transactions);
+    assert_eq!(reconciliation.total, ReconciliationTotals { count: 150, gross: 90000, fee: 22080, net: 72920 });
+    assert_eq!(reconciliation.by_type["charge"], ReconciliationTotals { count: 120, gross: 120000, fee: 7080, net: 112920 });
+    assert_eq!(reconciliation.by_type["refund"], ReconciliationTotals { count: 20, gross: -10000, fee: 0, net: -10000 });
+    assert_eq!(reconciliation.by_type["adjustment"], ReconciliationTotals { count: 10, gross: -15000, fee: 15000, net: -30000 });
+    assert!(!reconciliation.by_type.contains_key("payout"));
+    assert!(reconciliation.is_balanced());
+
+    // A transaction missing from the payout leaves a discrepancy
+    let reconciliation = PayoutReconciliation::new(&payout, &transactions[1..]);
+    assert_eq!(reconciliation.discrepancy(), 941);
+    assert!(!reconciliation.is_balanced());
+
+    // Transactions in another currency can't be summed with the payout
+    let mut fixture = fixture;
+    fixture.push(txn("txn_fx".to_string(), "charge", "eur", 0, 0));
+    let transactions: Vec<BalanceTransaction> = json::from_str(&format!("[{}]", fixture.join(","))).unwrap();
+    let reconciliation = PayoutReconciliation::new(&payout, &transactions);
+    assert_eq!(reconciliation.discrepancy(), 0);
+    assert_eq!(reconciliation.foreign_currency, vec!["txn_fx".to_string()]);
+    assert!(!reconciliation.is_balanced());
+}
+
+#[test]
+fn deserialize_invoice_dunning() {
+    use stripe::{DeclineCode, ErrorCode, ErrorType, Invoice, InvoicePayParams, InvoiceStatus, Subscription, SubscriptionStatus};
+    use stripe::test_helpers::fixture;
+
+    let invoice: Invoice = json::from_str(
+        r#"{
+            "id": "in_123",
+            "amount_due": 2000,
+            "attempt_count": 2,
+            "attempted": true,
+            "closed": false,
+            "currency": "usd",
+            "customer": "cus_123",
+            "date": 1504233902,
+            "ending_balance": 0,
+            "forgiven": false,
+            "last_finalization_error": {
+                "type": "card_error",
+                "code": "card_declined",
+                "decline_code": "insufficient_funds",
+                "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+                "message": "Your card has insufficient funds.",
+                "payment_method": "pm_123"
+            },
+            "lines": {"object": "list", "data": [], "has_more": false, "total_count": 0, "url": "/v1/invoices/in_123/lines"},
+            "livemode": false,
+            "metadata": {},
+            "next_payment_attempt": 1504493102,
+            "paid": false,
+            "payment_settings": {"default_mandate": null, "payment_method_types": ["card", "sepa_debit"]},
+            "period_end": 1504233902,
+            "period_start": 1504233902,
+            "starting_balance": 0,
+            "status": "open",
+            "subscription": "sub_123",
+            "subtotal": 2000,
+            "total": 2000
+        }"#,
+    ).unwrap();
+    assert_eq!(invoice.status, Some(InvoiceStatus::Open));
+    assert_eq!(invoice.attempt_count, 2);
+    assert!(invoice.attempted);
+    assert_eq!(invoice.next_payment_attempt, Some(1504493102));
+    let error = invoice.last_finalization_error.unwrap();
+    assert_eq!(error.error_type, ErrorType::Card);
+    assert_eq!(error.code, Some(ErrorCode::CardDeclined));
+    assert_eq!(error.decline_code, Some(DeclineCode::InsufficientFunds));
+    let settings = invoice.payment_settings.unwrap();
+    assert_eq!(settings.payment_method_types, Some(vec!["card".to_string(), "sepa_debit".to_string()]));
+    assert_eq!(settings.default_mandate, None);
+
+    // The invoice's subscription goes past due, then unpaid once retries are exhausted
+    for &(status, ref expected) in &[("past_due", SubscriptionStatus::PastDue), ("unpaid", SubscriptionStatus::Unpaid)] {
+        let mut payload = fixture("customer.subscription.deleted").unwrap();
+        payload["id"] = json::Value::String("sub_123".to_string());
+        payload["status"] = json::Value::String(status.to_string());
+        payload["canceled_at"] = json::Value::Null;
+        payload["ended_at"] = json::Value::Null;
+        let subscription: Subscription = json::from_value(payload).unwrap();
+        assert_eq!(Some(subscription.id.as_str()), invoice.subscription.as_ref().map(|id| id.as_str()));
+        assert_eq!(&subscription.status, expected);
+    }
+
+    let params = InvoicePayParams { forgive: Some(true), off_session: Some(true), ..Default::default() };
+    assert_eq!(urldecode(qs::to_string(&params).unwrap()), "forgive=true&off_session=true");
+}
+
+#[test]
+fn serialize_automatic_payment_methods() {
+    use stripe::{AllowRedirects, AutomaticPaymentMethods, Currency, PaymentIntentParams};
+
+    let mut params = PaymentIntentParams::default();
+    params.amount = Some(1000);
+    params.currency = Some(Currency::USD);
+    params.automatic_payment_methods = Some(AutomaticPaymentMethods { enabled: true, allow_redirects: None });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount=1000&currency=usd&automatic_payment_methods[enabled]=true"
+    );
+
+    params.automatic_payment_methods = Some(AutomaticPaymentMethods {
+        enabled: true,
+        allow_redirects: Some(AllowRedirects::Never),
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "amount=1000&currency=usd&automatic_payment_methods[enabled]=true&automatic_payment_methods[allow_redirects]=never"
+    );
+}
+
+#[test]
+fn automatic_payment_methods_on_intents() {
+    use stripe::{AllowRedirects, AutomaticPaymentMethods, PaymentIntent, SetupIntent, SetupIntentParams};
+
+    let mut params = SetupIntentParams::default();
+    params.customer = Some("cus_123");
+    params.automatic_payment_methods = Some(AutomaticPaymentMethods {
+        enabled: true,
+        allow_redirects: Some(AllowRedirects::Always),
+    });
+    assert_eq!(
+        urldecode(qs::to_string(&params).unwrap()),
+        "automatic_payment_methods[enabled]=true&automatic_payment_methods[allow_redirects]=always&customer=cus_123"
+    );
+
+    let intent: PaymentIntent = json::from_str(
+        r#"{
+            "id": "pi_123", "object": "payment_intent", "amount": 2000, "amount_capturable": 0,
+            "amount_received": 0, "automatic_payment_methods": {"allow_redirects": "never", "enabled": true},
+            "capture_method": "automatic", "confirmation_method": "automatic", "created": 1500000000,
+            "currency": "usd", "livemode": false, "metadata": {},
+            "payment_method_types": ["card", "link"], "status": "requires_payment_method"
+        }"#,
+    ).unwrap();
+    assert_eq!(
+        intent.automatic_payment_methods,
+        Some(AutomaticPaymentMethods { enabled: true, allow_redirects: Some(AllowRedirects::Never) })
+    );
+
+    let intent: SetupIntent = json::from_str(
+        r#"{
+            "id": "seti_123", "object": "setup_intent", "automatic_payment_methods": null,
+            "cancellation_reason": null, "client_secret": null, "created": 1500000000, "customer": null,
+            "description": null, "last_setup_error": null, "livemode": false, "metadata": {},
+            "next_action": null, "payment_method": null, "payment_method_types": ["card"],
+            "status": "requires_payment_method", "usage": "off_session"
+        }"#,
+    ).unwrap();
+    assert_eq!(intent.automatic_payment_methods, None);
+}
+
+#[test]
+fn deserialize_confirmation_token() {
+    use stripe::{ConfirmationToken, PaymentMethodDetails};
+
+    let token: ConfirmationToken = json::from_str(
+        r#"{
+            "id": "ctoken_123",
+            "object": "confirmation_token",
+            "created": 1500000000,
+            "expires_at": 1500043200,
+            "livemode": false,
+            "payment_intent": null,
+            "payment_method_options": {"card": {"cvc_token": null}},
+            "payment_method_preview": {
+                "billing_details": {"address": null, "email": "jane@example.com", "name": "Jane Doe", "phone": null},
+                "customer": null,
+                "type": "card",
+                "card": {
+                    "brand": "visa", "country": "US", "exp_month": 8, "exp_year": 2030, "funding": "credit",
+                    "last4": "4242", "networks": {"available": ["visa"], "preferred": null}, "wallet": null
+                }
+            },
+            "return_url": "https://example.com/return",
+            "setup_future_usage": "off_session",
+            "setup_intent": null,
+            "shipping": {"address": {"city": "Springfield", "country": "US", "line1": "1 Main St"}, "name": "Jane Doe"},
+            "use_stripe_sdk": false
+        }"#,
+    ).unwrap();
+    assert_eq!(token.expires_at, Some(1500043200));
+    assert_eq!(token.setup_future_usage, Some("off_session".to_string()));
+    assert_eq!(token.shipping.unwrap().name, Some("Jane Doe".to_string()));
+    assert!(token.payment_method_options.unwrap().card.unwrap().cvc_token.is_none());
+    let preview = token.payment_method_preview.unwrap();
+    assert_eq!(preview.billing_details.email, Some("jane@example.com".to_string()));
+    match preview.details {
+        PaymentMethodDetails::Card(card) => {
+            assert_eq!(card.brand, Some("visa".to_string()));
+            assert_eq!(card.last4, Some("4242".to_string()));
+        }
+        other => panic!("expected card details, got {:?}", other),
+    }
+}
+
 fn urldecode(input: String) -> String {
     input.replace("%5B", "[").replace("%5D", "]")
 }